@@ -0,0 +1,119 @@
+use std::{
+	cell::{Cell, RefCell},
+	rc::{Rc, Weak},
+};
+
+use wasm_bindgen::{prelude::Closure, JsCast};
+use web_sys::window;
+
+use crate::common::Number;
+
+use super::{Interpolate, Tween};
+
+/// Calls a handler on every animation frame (`requestAnimationFrame`) with the frame's timestamp (in milliseconds).
+///
+/// The handler returns whether it wants to be called on the next frame again.
+///
+/// # Remarks:
+/// - Like an [EventListener](crate::events::EventListener), the loop stops when this object is dropped.
+/// - The handler may call [AnimationFrame::start] or [AnimationFrame::stop] on the object (e.g. through an `Rc`).
+pub struct AnimationFrame {
+	inner: Rc<Inner>,
+}
+
+type Handler = Box<dyn FnMut(Number) -> bool>;
+
+struct Inner {
+	handler: RefCell<Option<Handler>>,
+	/// the id of the requested frame
+	request: Cell<Option<i32>>,
+	/// incremented by every start/stop (so a running handler can tell whether it has been replaced)
+	generation: Cell<usize>,
+}
+
+impl AnimationFrame {
+	pub fn new() -> Self {
+		Self {
+			inner: Rc::new(Inner { handler: RefCell::new(None), request: Cell::new(None), generation: Cell::new(0) }),
+		}
+	}
+
+	/// Calls the handler on every frame until it returns `false` (or the loop is stopped).
+	///
+	/// Replaces the current handler.
+	pub fn start(&self, handler: impl FnMut(Number) -> bool + 'static) -> Result<(), ()> {
+		self.stop();
+		if let Ok(mut current) = self.inner.handler.try_borrow_mut() {
+			*current = Some(Box::new(handler));
+		}
+		Inner::request(&self.inner)
+	}
+
+	/// Animates the tween and calls `apply` with its value on every frame until it's finished.
+	pub fn start_tween<T: Interpolate + Clone + 'static>(
+		&self,
+		mut tween: Tween<T>,
+		mut apply: impl FnMut(T) + 'static,
+	) -> Result<(), ()> {
+		self.start(move |timestamp| {
+			apply(tween.step(timestamp));
+			!tween.is_finished()
+		})
+	}
+
+	/// Stops calling the handler.
+	pub fn stop(&self) {
+		self.inner.generation.set(self.inner.generation.get().wrapping_add(1));
+		if let Some(request) = self.inner.request.take() {
+			_ = window().map(|window| window.cancel_animation_frame(request));
+		}
+		if let Ok(mut handler) = self.inner.handler.try_borrow_mut() {
+			*handler = None;
+		}
+	}
+
+	pub fn is_running(&self) -> bool {
+		self.inner.request.get().is_some()
+	}
+}
+
+impl Inner {
+	fn request(this: &Rc<Self>) -> Result<(), ()> {
+		let weak = Rc::downgrade(this);
+		// the closure is freed after it's been called (if the frame is canceled, it leaks; that's the price for not having to keep it around)
+		let callback = Closure::once_into_js(move |timestamp: Number| Self::frame(weak, timestamp));
+		let request = window().ok_or(())?.request_animation_frame(callback.unchecked_ref()).map_err(|_| ())?;
+		this.request.set(Some(request));
+		Ok(())
+	}
+
+	fn frame(this: Weak<Self>, timestamp: Number) {
+		let Some(this) = this.upgrade() else { return };
+		this.request.set(None);
+		let generation = this.generation.get();
+		// take the handler out while it runs, so it can start/stop the loop
+		let Some(mut handler) = this.handler.try_borrow_mut().ok().and_then(|mut handler| handler.take()) else {
+			return;
+		};
+		let again = handler(timestamp);
+		if this.generation.get() != generation || !again {
+			return;
+		}
+		if let Ok(mut current) = this.handler.try_borrow_mut() {
+			*current = Some(handler);
+		}
+		_ = Self::request(&this);
+	}
+}
+
+impl Default for AnimationFrame {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl Drop for AnimationFrame {
+	fn drop(&mut self) {
+		self.stop();
+	}
+}
@@ -0,0 +1,150 @@
+use std::f64::consts::PI;
+
+use crate::common::Number;
+
+/// How an animation progresses over time.
+///
+/// [Easing::apply] maps the elapsed fraction of the animation's duration (`0.0..=1.0`) to the progress of the value.
+/// The progress is 0 at the start and 1 at the end, but might leave that range in between (e.g. [Easing::ElasticOut] overshoots).
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub enum Easing {
+	#[default]
+	Linear,
+	QuadIn,
+	QuadOut,
+	QuadInOut,
+	CubicIn,
+	CubicOut,
+	CubicInOut,
+	ElasticIn,
+	ElasticOut,
+	ElasticInOut,
+	/// A damped spring (with a mass of 1) that is released at the start and pulls the value to its end.
+	///
+	/// The duration of the animation corresponds to 1 second of the simulated spring.
+	/// The spring is cut off at the end, so choose stiffness and damping so it's settled by then (see [Easing::spring]).
+	Spring {
+		stiffness: Number,
+		damping: Number,
+	},
+	/// Like the css `cubic-bezier(x1, y1, x2, y2)` timing function.
+	///
+	/// x1 and x2 need to be in `0.0..=1.0` (they are clamped).
+	CubicBezier(Number, Number, Number, Number),
+}
+
+impl Easing {
+	/// A spring that overshoots a bit and is settled after the duration.
+	pub fn spring() -> Self {
+		Self::Spring { stiffness: 100.0, damping: 10.0 }
+	}
+	/// The css `ease` timing function.
+	pub fn ease() -> Self {
+		Self::CubicBezier(0.25, 0.1, 0.25, 1.0)
+	}
+	/// The css `ease-in-out` timing function.
+	pub fn ease_in_out() -> Self {
+		Self::CubicBezier(0.42, 0.0, 0.58, 1.0)
+	}
+
+	/// The progress at the given fraction of the duration (which is clamped to `0.0..=1.0`).
+	pub fn apply(&self, t: Number) -> Number {
+		let t = match t.is_nan() {
+			true => 0.0,
+			false => t.clamp(0.0, 1.0),
+		};
+		if t == 0.0 || t == 1.0 {
+			return t;
+		}
+		match *self {
+			Self::Linear => t,
+			Self::QuadIn => t * t,
+			Self::QuadOut => 1.0 - (1.0 - t).powi(2),
+			Self::QuadInOut => match t < 0.5 {
+				true => 2.0 * t * t,
+				false => 1.0 - (-2.0 * t + 2.0).powi(2) / 2.0,
+			},
+			Self::CubicIn => t.powi(3),
+			Self::CubicOut => 1.0 - (1.0 - t).powi(3),
+			Self::CubicInOut => match t < 0.5 {
+				true => 4.0 * t.powi(3),
+				false => 1.0 - (-2.0 * t + 2.0).powi(3) / 2.0,
+			},
+			Self::ElasticIn => -(2.0 as Number).powf(10.0 * t - 10.0) * ((10.0 * t - 10.75) * ELASTIC_PERIOD).sin(),
+			Self::ElasticOut => (2.0 as Number).powf(-10.0 * t) * ((10.0 * t - 0.75) * ELASTIC_PERIOD).sin() + 1.0,
+			Self::ElasticInOut => {
+				let wave = ((20.0 * t - 11.125) * ELASTIC_IN_OUT_PERIOD).sin();
+				match t < 0.5 {
+					true => -(2.0 as Number).powf(20.0 * t - 10.0) * wave / 2.0,
+					false => (2.0 as Number).powf(-20.0 * t + 10.0) * wave / 2.0 + 1.0,
+				}
+			},
+			Self::Spring { stiffness, damping } => spring(stiffness, damping, t),
+			Self::CubicBezier(x1, y1, x2, y2) => cubic_bezier((x1.clamp(0.0, 1.0), y1), (x2.clamp(0.0, 1.0), y2), t),
+		}
+	}
+}
+
+const ELASTIC_PERIOD: Number = 2.0 * PI / 3.0;
+const ELASTIC_IN_OUT_PERIOD: Number = 2.0 * PI / 4.5;
+
+/// The position of a damped spring (mass 1) going from 0 to 1 after `t` seconds.
+fn spring(stiffness: Number, damping: Number, t: Number) -> Number {
+	if stiffness <= 0.0 {
+		return t;
+	}
+	let frequency = stiffness.sqrt();
+	let ratio = damping.max(0.0) / (2.0 * frequency);
+	let decay = (-ratio * frequency * t).exp();
+	match ratio {
+		r if r < 1.0 => {
+			let damped = frequency * (1.0 - r * r).sqrt();
+			1.0 - decay * ((damped * t).cos() + r * frequency / damped * (damped * t).sin())
+		},
+		r if r > 1.0 => {
+			let damped = frequency * (r * r - 1.0).sqrt();
+			1.0 - decay * ((damped * t).cosh() + r * frequency / damped * (damped * t).sinh())
+		},
+		_ => 1.0 - decay * (1.0 + frequency * t),
+	}
+}
+
+/// The y of the curve from (0, 0) to (1, 1) with the given control points at x.
+fn cubic_bezier((x1, y1): (Number, Number), (x2, y2): (Number, Number), x: Number) -> Number {
+	let coordinate = |p1: Number, p2: Number, s: Number| {
+		let ms = 1.0 - s;
+		3.0 * ms * ms * s * p1 + 3.0 * ms * s * s * p2 + s * s * s
+	};
+	let slope = |p1: Number, p2: Number, s: Number| {
+		let ms = 1.0 - s;
+		3.0 * ms * ms * p1 + 6.0 * ms * s * (p2 - p1) + 3.0 * s * s * (1.0 - p2)
+	};
+
+	// find the curve parameter for x: newton's method and bisection if that doesn't converge
+	let mut s = x;
+	for _ in 0..8 {
+		let error = coordinate(x1, x2, s) - x;
+		if error.abs() < 1e-7 {
+			return coordinate(y1, y2, s);
+		}
+		let derivative = slope(x1, x2, s);
+		if derivative.abs() < 1e-6 {
+			break;
+		}
+		s -= error / derivative;
+	}
+	let (mut low, mut high) = (0.0, 1.0);
+	s = x;
+	for _ in 0..50 {
+		let current = coordinate(x1, x2, s);
+		if (current - x).abs() < 1e-7 {
+			break;
+		}
+		match current < x {
+			true => low = s,
+			false => high = s,
+		}
+		s = (low + high) / 2.0;
+	}
+	coordinate(y1, y2, s)
+}
@@ -0,0 +1,36 @@
+use crate::common::{Bounds, Color, Number, Vector};
+
+/// Values that can be animated by blending between two of them.
+pub trait Interpolate {
+	/// The value at `t` between this one (`t = 0`) and the other one (`t = 1`).
+	///
+	/// `t` might be outside of `0.0..=1.0` (if the [Easing](super::Easing) overshoots).
+	/// Implementations extrapolate or clamp, whatever makes sense for the type.
+	fn interpolate(&self, other: &Self, t: Number) -> Self;
+}
+
+impl Interpolate for Number {
+	fn interpolate(&self, other: &Self, t: Number) -> Self {
+		self + (other - self) * t
+	}
+}
+
+impl Interpolate for Vector {
+	fn interpolate(&self, other: &Self, t: Number) -> Self {
+		*self + (*other - *self) * t
+	}
+}
+
+/// Interpolates start and size (so the size stays positive for positive sizes, unless `t` overshoots a lot).
+impl Interpolate for Bounds {
+	fn interpolate(&self, other: &Self, t: Number) -> Self {
+		Bounds::new(self.start().interpolate(&other.start(), t), self.size().interpolate(&other.size(), t))
+	}
+}
+
+/// Blends the colors like [Color::mix] (so `t` is clamped).
+impl Interpolate for Color {
+	fn interpolate(&self, other: &Self, t: Number) -> Self {
+		self.mix(other, t)
+	}
+}
@@ -0,0 +1,18 @@
+//! Animating values over time.
+//!
+//! - [Easing]: how an animation progresses
+//! - [Interpolate]: values that can be animated
+//! - [Tween]: a value animated between two states (stepped with explicit timestamps)
+//! - [AnimationFrame]: drives an animation with `requestAnimationFrame`
+
+mod easing;
+pub use easing::*;
+
+mod interpolate;
+pub use interpolate::*;
+
+mod tween;
+pub use tween::*;
+
+mod animation_frame;
+pub use animation_frame::*;
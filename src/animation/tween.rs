@@ -0,0 +1,149 @@
+use crate::common::Number;
+
+use super::{Easing, Interpolate};
+
+/// Animates a value from one state to another over a duration.
+///
+/// A tween doesn't read any clock: it's advanced by calling [Tween::step] with the current timestamp.
+/// That makes it deterministic (e.g. for tests) and lets it be driven by anything that provides timestamps,
+/// e.g. an [AnimationFrame](super::AnimationFrame) (whose timestamps are in milliseconds).
+///
+/// Durations, delays and timestamps just need to be in the same unit.
+///
+/// # Example
+/// ```text
+/// let mut tween = Tween::new(0.0, 100.0, 200.0).with_easing(Easing::CubicOut);
+/// tween.step(1000.0); // starts the tween: 0.0
+/// tween.step(1100.0); // half of the duration: 87.5
+/// tween.step(1200.0); // finished: 100.0
+/// ```
+#[derive(Clone, Debug)]
+pub struct Tween<T: Interpolate + Clone> {
+	from: T,
+	to: T,
+	duration: Number,
+	delay: Number,
+	easing: Easing,
+	/// the timestamp of the first step
+	started: Option<Number>,
+	/// the elapsed fraction of the duration at the last step
+	elapsed: Number,
+}
+
+impl<T: Interpolate + Clone> Tween<T> {
+	pub fn new(from: T, to: T, duration: Number) -> Self {
+		Self { from, to, duration, delay: 0.0, easing: Easing::Linear, started: None, elapsed: 0.0 }
+	}
+	pub fn with_easing(mut self, easing: Easing) -> Self {
+		self.easing = easing;
+		self
+	}
+	/// Waits for `delay` after the start before the value begins to change.
+	pub fn with_delay(mut self, delay: Number) -> Self {
+		self.delay = delay;
+		self
+	}
+
+	pub fn from(&self) -> &T {
+		&self.from
+	}
+	pub fn to(&self) -> &T {
+		&self.to
+	}
+	pub fn easing(&self) -> Easing {
+		self.easing
+	}
+
+	/// Starts (or restarts) the tween at the given timestamp.
+	///
+	/// This is optional: the first [Tween::step] starts the tween if it hasn't been started.
+	pub fn start_at(&mut self, timestamp: Number) {
+		self.started = Some(timestamp);
+		self.elapsed = 0.0;
+	}
+	/// Resets the tween to its start (it starts again with the next step).
+	pub fn reset(&mut self) {
+		self.started = None;
+		self.elapsed = 0.0;
+	}
+
+	/// Advances the tween to the given timestamp and returns the value at that time.
+	///
+	/// Timestamps before the start (or the last restart) are treated as the start.
+	pub fn step(&mut self, timestamp: Number) -> T {
+		let started = *self.started.get_or_insert(timestamp);
+		let time = timestamp - started - self.delay;
+		self.elapsed = match self.duration > 0.0 {
+			true => (time / self.duration).clamp(0.0, 1.0),
+			false => match time >= 0.0 {
+				true => 1.0,
+				false => 0.0,
+			},
+		};
+		self.value()
+	}
+
+	/// The value at the last step.
+	pub fn value(&self) -> T {
+		self.from.interpolate(&self.to, self.easing.apply(self.elapsed))
+	}
+	/// The elapsed fraction of the duration (`0.0..=1.0`) at the last step (without easing).
+	pub fn progress(&self) -> Number {
+		self.elapsed
+	}
+	pub fn is_started(&self) -> bool {
+		self.started.is_some()
+	}
+	pub fn is_finished(&self) -> bool {
+		self.elapsed >= 1.0
+	}
+
+	/// Animates towards a new target, starting from the current value at the given timestamp.
+	///
+	/// Use this to change the target while the tween is running without a jump.
+	pub fn retarget(&mut self, to: T, timestamp: Number) {
+		self.from = self.value();
+		self.to = to;
+		self.start_at(timestamp);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn steps_with_timestamps() {
+		let mut tween = Tween::new(10.0, 20.0, 100.0).with_delay(50.0);
+		assert_eq!(tween.step(1000.0), 10.0);
+		assert_eq!(tween.step(1050.0), 10.0);
+		assert_eq!(tween.step(1100.0), 15.0);
+		assert!(!tween.is_finished());
+		assert_eq!(tween.step(1200.0), 20.0);
+		assert!(tween.is_finished());
+
+		tween.retarget(0.0, 2000.0);
+		assert_eq!(tween.step(2100.0), 10.0);
+	}
+
+	#[test]
+	fn easings_start_at_0_and_end_at_1() {
+		let easings = [
+			Easing::Linear,
+			Easing::QuadInOut,
+			Easing::CubicInOut,
+			Easing::ElasticInOut,
+			Easing::spring(),
+			Easing::Spring { stiffness: 100.0, damping: 20.0 },
+			Easing::Spring { stiffness: 100.0, damping: 25.0 },
+			Easing::ease(),
+		];
+		for easing in easings {
+			assert_eq!(easing.apply(0.0), 0.0);
+			assert_eq!(easing.apply(1.0), 1.0);
+			assert!((easing.apply(0.999) - 1.0).abs() < 0.05, "{easing:?}");
+		}
+		assert!((Easing::CubicBezier(0.0, 0.0, 1.0, 1.0).apply(0.3) - 0.3).abs() < 1e-6);
+		assert!((Easing::ease_in_out().apply(0.5) - 0.5).abs() < 1e-6);
+	}
+}
@@ -0,0 +1,191 @@
+use std::{
+	cell::{Cell, RefCell},
+	rc::{Rc, Weak},
+	str::FromStr,
+};
+
+use crate::{
+	common::Color,
+	components::{Checkbox, ColorSelector, Slider},
+	errors::{ContextInUseError, ErrorHandler},
+	events::CustomEventListener,
+	Context, Lens,
+};
+
+/// A widget that shows a value and reports when the user changes it (see [Binding]).
+pub trait Bindable<V> {
+	/// Fired when the user changes the value.
+	fn on_change(&self) -> &CustomEventListener<V>;
+	/// Shows the value (without firing `on_change`).
+	fn show(&self, value: &V);
+}
+
+impl<T: FromStr + ToString + Clone + std::ops::Add<T, Output = T> + std::ops::Sub<T, Output = T> + 'static> Bindable<T>
+	for Slider<T>
+{
+	fn on_change(&self) -> &CustomEventListener<T> {
+		&self.on_change
+	}
+	fn show(&self, value: &T) {
+		self.set_value(value.clone());
+	}
+}
+impl Bindable<bool> for Checkbox {
+	fn on_change(&self) -> &CustomEventListener<bool> {
+		&self.on_change
+	}
+	fn show(&self, value: &bool) {
+		self.set_value(*value);
+	}
+}
+impl Bindable<Color> for ColorSelector {
+	fn on_change(&self) -> &CustomEventListener<Color> {
+		&self.on_change
+	}
+	fn show(&self, value: &Color) {
+		self.set_value(value);
+	}
+}
+
+struct BindingInternal<V> {
+	widget: Rc<dyn Bindable<V>>,
+	read: Box<dyn Fn() -> Option<V>>,
+	write: Box<dyn Fn(V) -> bool>,
+	/// the value the widget shows
+	shown: RefCell<Option<V>>,
+	/// set while the value is written to the context (so it isn't shown again)
+	writing: Cell<bool>,
+}
+
+/// Connects a widget to a field of a [Context] in both directions.
+///
+/// - When the user changes the value, it's written to the field.
+/// - [refresh](Binding::refresh) shows the value of the field in the widget (e.g. in the `update()` of your component).
+///
+/// Failed accesses of the context are reported as [ContextInUseError] to its error handler.
+/// The binding takes over the `on_change` handler of the widget and removes it when dropped.
+///
+/// # Example
+/// ```text
+/// let zoom = Binding::new(slider.clone(), context.clone_for("zoom"), lens!(Model, settings.zoom));
+/// ...
+/// fn update(&self) -> Result<()> {
+///     self.zoom.refresh();
+///     Ok(())
+/// }
+/// ```
+pub struct Binding<V> {
+	internal: Rc<BindingInternal<V>>,
+}
+
+impl<V: Clone + PartialEq + 'static> Binding<V> {
+	/// Binds the widget to the field and shows its current value.
+	pub fn new<T: 'static, H: ErrorHandler<ContextInUseError> + 'static>(
+		widget: Rc<impl Bindable<V> + 'static>,
+		context: Context<T, H>,
+		lens: Lens<T, V>,
+	) -> Self {
+		let context = Rc::new(context);
+		let internal = Rc::new(BindingInternal {
+			widget,
+			read: Box::new({
+				let (context, lens) = (context.clone(), lens.clone());
+				move || context.read(&lens)
+			}),
+			write: Box::new(move |value| context.write(&lens, value)),
+			shown: RefCell::new(None),
+			writing: Cell::new(false),
+		});
+
+		let weak: Weak<BindingInternal<V>> = Rc::downgrade(&internal);
+		_ = internal.widget.on_change().set_handler(move |value: V| {
+			let Some(internal) = weak.upgrade() else { return };
+			// a write that triggers another change (e.g. through an update) must not loop
+			if internal.writing.replace(true) {
+				return;
+			}
+			if (internal.write)(value.clone()) {
+				*internal.shown.borrow_mut() = Some(value);
+			}
+			internal.writing.set(false);
+		});
+
+		let this = Self { internal };
+		this.refresh();
+		this
+	}
+
+	/// Shows the value of the field in the widget (if it changed since it was last shown).
+	///
+	/// # Returns
+	/// Whether the widget was changed.
+	pub fn refresh(&self) -> bool {
+		let internal = &self.internal;
+		if internal.writing.get() {
+			return false;
+		}
+		let Some(value) = (internal.read)() else { return false };
+		if internal.shown.borrow().as_ref() == Some(&value) {
+			return false;
+		}
+		internal.widget.show(&value);
+		*internal.shown.borrow_mut() = Some(value);
+		true
+	}
+}
+
+impl<V> Drop for Binding<V> {
+	fn drop(&mut self) {
+		self.internal.widget.on_change().remove_handler();
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		errors::{StringErrorHandler, TracksEnvironment},
+		lens,
+	};
+
+	struct Field {
+		on_change: CustomEventListener<i32>,
+		shown: RefCell<Vec<i32>>,
+	}
+	impl Bindable<i32> for Field {
+		fn on_change(&self) -> &CustomEventListener<i32> {
+			&self.on_change
+		}
+		fn show(&self, value: &i32) {
+			self.shown.borrow_mut().push(*value);
+		}
+	}
+
+	struct Model {
+		settings: (i32, i32),
+	}
+
+	#[test]
+	fn binds_both_ways() {
+		let (handler, errors) = StringErrorHandler::new("Test".to_owned());
+		let context = Context::make(Model { settings: (1, 2) }, handler);
+		let field = Rc::new(Field { on_change: CustomEventListener::new(), shown: RefCell::new(Vec::new()) });
+		let binding = Binding::new(field.clone(), context.clone_for("binding"), lens!(Model, settings.1));
+
+		// user input is written and not shown again
+		_ = field.on_change.fire(5);
+		assert_eq!(context.access().unwrap().settings.1, 5);
+		assert!(!binding.refresh());
+
+		// changes elsewhere are shown
+		context.access_mut().unwrap().settings.1 = 7;
+		assert!(binding.refresh());
+		assert_eq!(*field.shown.borrow(), vec![2, 7]);
+
+		// the context being in use is reported
+		let data = context.access_mut().unwrap();
+		_ = field.on_change.fire(9);
+		drop(data);
+		assert_eq!(errors.get().len(), 1);
+	}
+}
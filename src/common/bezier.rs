@@ -0,0 +1,303 @@
+use super::{Bounds, Curve, Line, Number, Vector};
+
+/// How often a curve is subdivided at most when flattening it (so degenerate input can't recurse forever).
+const MAX_SUBDIVISIONS: usize = 16;
+
+/// A quadratic Bézier curve (one control point).
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QuadraticBezier {
+	pub start: Vector,
+	pub control: Vector,
+	pub end: Vector,
+}
+
+impl QuadraticBezier {
+	pub fn new(start: Vector, control: Vector, end: Vector) -> Self {
+		Self { start, control, end }
+	}
+
+	/// The derivative (the tangent, not normalized) at parameter `t`.
+	pub fn derivative_at(&self, t: Number) -> Vector {
+		let t = t.clamp(0.0, 1.0);
+		2.0 * (1.0 - t) * (self.control - self.start) + 2.0 * t * (self.end - self.control)
+	}
+
+	/// The same curve as a [CubicBezier].
+	pub fn to_cubic(&self) -> CubicBezier {
+		CubicBezier::new(
+			self.start,
+			self.start + (self.control - self.start) * (2.0 / 3.0),
+			self.end + (self.control - self.end) * (2.0 / 3.0),
+			self.end,
+		)
+	}
+
+	/// How far the curve deviates from the straight line between start and end (at most).
+	fn flatness(&self) -> Number {
+		Line::new(self.start, self.end).distance_to(self.control) / 2.0
+	}
+}
+
+impl Curve for QuadraticBezier {
+	fn point_at(&self, t: Number) -> Vector {
+		let t = t.clamp(0.0, 1.0);
+		let mt = 1.0 - t;
+		mt * mt * self.start + 2.0 * mt * t * self.control + t * t * self.end
+	}
+	fn bounds(&self) -> Bounds {
+		let mut bounds = Bounds::new_with_end(self.start, self.end).normalized();
+		for axis in 0..2 {
+			let denominator = self.start[axis] - 2.0 * self.control[axis] + self.end[axis];
+			if denominator != 0.0 {
+				include_extremum(&mut bounds, self, (self.start[axis] - self.control[axis]) / denominator);
+			}
+		}
+		bounds
+	}
+	fn flatten_parametric(&self, tolerance: Number) -> Vec<(Number, Vector)> {
+		flatten(self, tolerance, &|curve: &Self| curve.flatness())
+	}
+	fn split_at(&self, t: Number) -> (Self, Self) {
+		let t = t.clamp(0.0, 1.0);
+		let a = lerp(self.start, self.control, t);
+		let b = lerp(self.control, self.end, t);
+		let middle = lerp(a, b, t);
+		(Self::new(self.start, a, middle), Self::new(middle, b, self.end))
+	}
+
+	fn start(&self) -> Vector {
+		self.start
+	}
+	fn end(&self) -> Vector {
+		self.end
+	}
+}
+
+/// A cubic Bézier curve (two control points).
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CubicBezier {
+	pub start: Vector,
+	pub control1: Vector,
+	pub control2: Vector,
+	pub end: Vector,
+}
+
+impl CubicBezier {
+	pub fn new(start: Vector, control1: Vector, control2: Vector, end: Vector) -> Self {
+		Self { start, control1, control2, end }
+	}
+
+	/// The derivative (the tangent, not normalized) at parameter `t`.
+	pub fn derivative_at(&self, t: Number) -> Vector {
+		let t = t.clamp(0.0, 1.0);
+		let mt = 1.0 - t;
+		3.0 * mt * mt * (self.control1 - self.start)
+			+ 6.0 * mt * t * (self.control2 - self.control1)
+			+ 3.0 * t * t * (self.end - self.control2)
+	}
+
+	/// How far the curve deviates from the straight line between start and end (at most).
+	fn flatness(&self) -> Number {
+		let chord = Line::new(self.start, self.end);
+		0.75 * Number::max(chord.distance_to(self.control1), chord.distance_to(self.control2))
+	}
+}
+
+impl Curve for CubicBezier {
+	fn point_at(&self, t: Number) -> Vector {
+		let t = t.clamp(0.0, 1.0);
+		let mt = 1.0 - t;
+		mt * mt * mt * self.start
+			+ 3.0 * mt * mt * t * self.control1
+			+ 3.0 * mt * t * t * self.control2
+			+ t * t * t * self.end
+	}
+	fn bounds(&self) -> Bounds {
+		let mut bounds = Bounds::new_with_end(self.start, self.end).normalized();
+		for axis in 0..2 {
+			let (p0, p1, p2, p3) = (self.start[axis], self.control1[axis], self.control2[axis], self.end[axis]);
+			// the derivative (divided by 3) is a * t^2 + b * t + c
+			let a = -p0 + 3.0 * p1 - 3.0 * p2 + p3;
+			let b = 2.0 * (p0 - 2.0 * p1 + p2);
+			let c = p1 - p0;
+			if a.abs() < 1e-12 {
+				if b != 0.0 {
+					include_extremum(&mut bounds, self, -c / b);
+				}
+				continue;
+			}
+			let discriminant = b * b - 4.0 * a * c;
+			if discriminant < 0.0 {
+				continue;
+			}
+			let root = discriminant.sqrt();
+			include_extremum(&mut bounds, self, (-b + root) / (2.0 * a));
+			include_extremum(&mut bounds, self, (-b - root) / (2.0 * a));
+		}
+		bounds
+	}
+	fn flatten_parametric(&self, tolerance: Number) -> Vec<(Number, Vector)> {
+		flatten(self, tolerance, &|curve: &Self| curve.flatness())
+	}
+	fn split_at(&self, t: Number) -> (Self, Self) {
+		let t = t.clamp(0.0, 1.0);
+		let a = lerp(self.start, self.control1, t);
+		let b = lerp(self.control1, self.control2, t);
+		let c = lerp(self.control2, self.end, t);
+		let ab = lerp(a, b, t);
+		let bc = lerp(b, c, t);
+		let middle = lerp(ab, bc, t);
+		(Self::new(self.start, a, ab, middle), Self::new(middle, bc, c, self.end))
+	}
+
+	fn start(&self) -> Vector {
+		self.start
+	}
+	fn end(&self) -> Vector {
+		self.end
+	}
+}
+
+impl From<QuadraticBezier> for CubicBezier {
+	fn from(value: QuadraticBezier) -> Self {
+		value.to_cubic()
+	}
+}
+
+fn lerp(a: Vector, b: Vector, t: Number) -> Vector {
+	a + (b - a) * t
+}
+
+/// Grows the bounds to include the point at `t`, if `t` is inside the curve.
+fn include_extremum(bounds: &mut Bounds, curve: &impl Curve, t: Number) {
+	if t > 0.0 && t < 1.0 {
+		bounds.merge(&Bounds::from(curve.point_at(t)));
+	}
+}
+
+/// Flattens the curve by subdividing it until each part is flat enough.
+fn flatten<C: Curve + Sized>(
+	curve: &C,
+	tolerance: Number,
+	flatness: &dyn Fn(&C) -> Number,
+) -> Vec<(Number, Vector)> {
+	let mut points = vec![(0.0, curve.start())];
+	flatten_part(curve, (0.0, 1.0), tolerance, flatness, 0, &mut points);
+	points
+}
+fn flatten_part<C: Curve + Sized>(
+	curve: &C,
+	(t0, t1): (Number, Number),
+	tolerance: Number,
+	flatness: &dyn Fn(&C) -> Number,
+	depth: usize,
+	points: &mut Vec<(Number, Vector)>,
+) {
+	if depth >= MAX_SUBDIVISIONS || flatness(curve) <= tolerance {
+		points.push((t1, curve.end()));
+		return;
+	}
+	let middle = (t0 + t1) / 2.0;
+	let (first, second) = curve.split_at(0.5);
+	flatten_part(&first, (t0, middle), tolerance, flatness, depth + 1, points);
+	flatten_part(&second, (middle, t1), tolerance, flatness, depth + 1, points);
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::common::CURVE_TOLERANCE;
+
+	fn assert_close(actual: Vector, expected: Vector) {
+		assert!((actual - expected).length() < 1e-9, "{actual:?} != {expected:?}");
+	}
+	/// A cubic whose y has a maximum and whose x has a minimum and a maximum between start and end.
+	fn s_curve() -> CubicBezier {
+		CubicBezier::new(Vector::new(0.0, 0.0), Vector::new(4.0, 1.0), Vector::new(-3.0, 1.0), Vector::new(1.0, 0.0))
+	}
+	fn arch() -> CubicBezier {
+		CubicBezier::new(Vector::new(0.0, 0.0), Vector::new(0.0, 1.0), Vector::new(1.0, 1.0), Vector::new(1.0, 0.0))
+	}
+
+	#[test]
+	fn evaluates() {
+		assert_eq!(arch().point_at(0.0), Vector::new(0.0, 0.0));
+		assert_close(arch().point_at(0.5), Vector::new(0.5, 0.75));
+		assert_eq!(arch().point_at(1.5), Vector::new(1.0, 0.0));
+
+		let quadratic = QuadraticBezier::new(Vector::new(0.0, 0.0), Vector::new(1.0, 2.0), Vector::new(2.0, 0.0));
+		assert_close(quadratic.point_at(0.5), Vector::new(1.0, 1.0));
+		for t in [0.0, 0.2, 0.5, 0.9, 1.0] {
+			assert_close(quadratic.to_cubic().point_at(t), quadratic.point_at(t));
+		}
+	}
+
+	#[test]
+	fn splits_continuously() {
+		let curve = s_curve();
+		let (first, second) = curve.split_at(0.3);
+		assert_eq!(first.end, second.start);
+		assert_close(first.end, curve.point_at(0.3));
+		assert_close(first.point_at(0.5), curve.point_at(0.15));
+		assert_close(second.point_at(0.5), curve.point_at(0.65));
+		// the tangents match too
+		let (incoming, outgoing) = (first.derivative_at(1.0), second.derivative_at(0.0));
+		assert!(incoming.cross(&outgoing).abs() < 1e-9 && incoming.dot(&outgoing) > 0.0);
+	}
+
+	#[test]
+	fn has_tight_bounds() {
+		assert_eq!(arch().bounds().size().y, 0.75);
+
+		let curve = s_curve();
+		let bounds = curve.bounds();
+		let samples: Vec<Vector> = (0..=1000).map(|i| curve.point_at(i as Number / 1000.0)).collect();
+		let (min, max) = samples.iter().fold((samples[0], samples[0]), |(min, max), p| (min.min(p), max.max(p)));
+		// the extrema lie between start and end, so the bounds are larger than the ones of start and end
+		assert!(bounds.left() < 0.0 && bounds.right() > 1.0 && bounds.bottom() > 0.0);
+		assert!((bounds.min() - min).length() < 1e-4 && (bounds.max() - max).length() < 1e-4);
+		assert!(samples.iter().all(|point| bounds.contains(*point)));
+	}
+
+	#[test]
+	fn flattens_within_tolerance() {
+		let curve = s_curve();
+		for tolerance in [0.1, 0.01, 0.001] {
+			let points = curve.flatten_parametric(tolerance);
+			assert_eq!(points.first(), Some(&(0.0, curve.start)));
+			assert_eq!(points.last(), Some(&(1.0, curve.end)));
+			for pair in points.windows(2) {
+				let [(t0, p0), (t1, p1)] = [pair[0], pair[1]];
+				let chord = Line::new(p0, p1);
+				for i in 0..=20 {
+					let point = curve.point_at(t0 + (t1 - t0) * i as Number / 20.0);
+					assert!(chord.distance_to(point) <= tolerance + 1e-12);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn measures_length() {
+		// the integral of |(2, 4 - 8t)| from 0 to 1
+		let expected = 2.957_885_715;
+		let quadratic = QuadraticBezier::new(Vector::new(0.0, 0.0), Vector::new(1.0, 2.0), Vector::new(2.0, 0.0));
+		// the chords are shorter than the curve
+		for length in [quadratic.length(), quadratic.to_cubic().length()] {
+			assert!(length <= expected && expected - length < CURVE_TOLERANCE);
+		}
+		assert!((quadratic.arc_lengths(1e-4).total() - expected).abs() < 1e-4);
+		assert!((quadratic.to_cubic().arc_lengths(1e-4).total() - expected).abs() < 1e-4);
+	}
+
+	#[test]
+	fn finds_the_closest_point() {
+		let closest = arch().closest_point(Vector::new(0.5, 2.0));
+		assert!((closest.t - 0.5).abs() < 1e-2);
+		assert!((closest.point - Vector::new(0.5, 0.75)).length() < 1e-3);
+		assert!((closest.distance - 1.25).abs() < 1e-3);
+		assert!((arch().distance_to(Vector::new(1.0, -1.0)) - 1.0).abs() < 1e-9);
+	}
+}
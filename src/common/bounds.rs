@@ -1,38 +1,169 @@
-use super::Vector;
+use super::{Number, Space, TypedVector, Unspecified};
 
-#[derive(Clone)]
-pub struct Bounds {
-	start: Vector,
-	size: Vector,
+/// An axis aligned rectangle, defined by its start (corner) and size.
+///
+/// The size may be negative (e.g. if the bounds were created by dragging from bottom-right to top-left).
+/// Unless noted otherwise methods treat the bounds as the area between [Bounds::min] and [Bounds::max], so they work either way.
+/// Use [Bounds::normalized] to get bounds with a non-negative size.
+///
+/// Like [TypedVector], the bounds are in a coordinate [Space]; [Bounds] are the ones of an unspecified space.
+///
+/// Serialized (with the `serde` feature) as `{ "start": [x, y], "size": [x, y] }`.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(bound = ""))]
+pub struct TypedBounds<S: Space> {
+	start: TypedVector<S>,
+	size: TypedVector<S>,
 }
 
-impl Bounds {
-	pub fn new(start: Vector, size: Vector) -> Self {
+/// [TypedBounds] that don't know which coordinate space they are in.
+pub type Bounds = TypedBounds<Unspecified>;
+
+impl<S: Space> TypedBounds<S> {
+	pub fn new(start: TypedVector<S>, size: TypedVector<S>) -> Self {
 		Self { start, size }
 	}
-	pub fn new_with_end(start: Vector, end: Vector) -> Self {
+	pub fn new_with_end(start: TypedVector<S>, end: TypedVector<S>) -> Self {
 		Self::new(start, end - start)
 	}
 
-	pub fn start(&self) -> Vector {
+	pub fn start(&self) -> TypedVector<S> {
 		self.start.clone()
 	}
-	pub fn size(&self) -> Vector {
+	pub fn size(&self) -> TypedVector<S> {
 		self.size.clone()
 	}
-	pub fn end(&self) -> Vector {
+	pub fn end(&self) -> TypedVector<S> {
 		self.start.clone() + self.size.clone()
 	}
-	pub fn min(&self) -> Vector {
+	pub fn min(&self) -> TypedVector<S> {
 		self.end().min(&self.start)
 	}
-	pub fn max(&self) -> Vector {
+	pub fn max(&self) -> TypedVector<S> {
 		self.end().max(&self.start)
 	}
 
-	pub fn contains(&self, point: Vector) -> bool {
+	/// The same bounds with a non-negative size (i.e. start is [Bounds::min] and end is [Bounds::max]).
+	pub fn normalized(&self) -> Self {
+		Self::new_with_end(self.min(), self.max())
+	}
+	/// Makes the size non-negative without changing the covered area (see [Bounds::normalized]).
+	pub fn normalize(&mut self) {
+		*self = self.normalized();
+	}
+
+	pub fn width(&self) -> Number {
+		self.size.x.abs()
+	}
+	pub fn height(&self) -> Number {
+		self.size.y.abs()
+	}
+	pub fn area(&self) -> Number {
+		self.width() * self.height()
+	}
+	pub fn center(&self) -> TypedVector<S> {
+		self.start + self.size / 2.0
+	}
+
+	/// The smallest x coordinate.
+	pub fn left(&self) -> Number {
+		self.min().x
+	}
+	/// The largest x coordinate.
+	pub fn right(&self) -> Number {
+		self.max().x
+	}
+	/// The smallest y coordinate (y grows downwards, like in the DOM).
+	pub fn top(&self) -> Number {
+		self.min().y
+	}
+	/// The largest y coordinate (y grows downwards, like in the DOM).
+	pub fn bottom(&self) -> Number {
+		self.max().y
+	}
+	pub fn top_left(&self) -> TypedVector<S> {
+		self.min()
+	}
+	pub fn top_right(&self) -> TypedVector<S> {
+		TypedVector::new(self.right(), self.top())
+	}
+	pub fn bottom_left(&self) -> TypedVector<S> {
+		TypedVector::new(self.left(), self.bottom())
+	}
+	pub fn bottom_right(&self) -> TypedVector<S> {
+		self.max()
+	}
+	/// The four corners (clockwise, starting at the top left).
+	pub fn corners(&self) -> [TypedVector<S>; 4] {
+		[self.top_left(), self.top_right(), self.bottom_right(), self.bottom_left()]
+	}
+
+	pub fn contains(&self, point: TypedVector<S>) -> bool {
 		point.x >= self.min().x && point.y >= self.min().y && point.x <= self.max().x && point.y <= self.max().y
 	}
+	/// Whether the other bounds lie completely inside these bounds.
+	pub fn contains_bounds(&self, other: &Self) -> bool {
+		self.contains(other.min()) && self.contains(other.max())
+	}
+	/// Whether these bounds and the other bounds overlap (touching counts).
+	pub fn intersects(&self, other: &Self) -> bool {
+		let (min, max) = (self.min(), self.max());
+		let (other_min, other_max) = (other.min(), other.max());
+		min.x <= other_max.x && other_min.x <= max.x && min.y <= other_max.y && other_min.y <= max.y
+	}
+	/// The area covered by both these and the other bounds.
+	///
+	/// Returns `None` if they don't intersect.
+	/// The result is always normalized.
+	pub fn intersection(&self, other: &Self) -> Option<Self> {
+		if !self.intersects(other) {
+			return None;
+		}
+		Some(Self::new_with_end(self.min().max(&other.min()), self.max().min(&other.max())))
+	}
+
+	/// The point inside these bounds that is closest to the given point.
+	pub fn clamp_point(&self, point: TypedVector<S>) -> TypedVector<S> {
+		point.max(&self.min()).min(&self.max())
+	}
+	/// The other bounds moved (not resized) so they lie inside these bounds.
+	///
+	/// If the other bounds are larger than these bounds in some direction, they are aligned to the start (top/left).
+	/// The result is always normalized.
+	pub fn clamp_bounds(&self, other: &Self) -> Self {
+		let other = other.normalized();
+		let max_start = self.max() - other.size;
+		let start = other.start.min(&max_start).max(&self.min());
+		Self::new(start, other.size)
+	}
+
+	/// The other bounds scaled (keeping their aspect ratio) to the largest size that fits inside these bounds, centered.
+	///
+	/// Like `object-fit: contain` in css.
+	///
+	/// If the other bounds have a zero width or height, only the other axis is scaled
+	/// (if both are zero, the result is an empty bounds at the center).
+	pub fn fit(&self, other: &Self) -> Self {
+		self.scale_centered(other, Number::min)
+	}
+	/// The other bounds scaled (keeping their aspect ratio) to the smallest size that covers these bounds, centered.
+	///
+	/// Like `object-fit: cover` in css.
+	///
+	/// Zero sizes of the other bounds are handled like in [Bounds::fit].
+	pub fn fill(&self, other: &Self) -> Self {
+		self.scale_centered(other, Number::max)
+	}
+	fn scale_centered(&self, other: &Self, choose: fn(Number, Number) -> Number) -> Self {
+		let ratio = |size: Number, other: Number| (other != 0.0).then(|| size / other);
+		let scale = match (ratio(self.width(), other.width()), ratio(self.height(), other.height())) {
+			(Some(x), Some(y)) => choose(x, y),
+			(Some(scale), None) | (None, Some(scale)) => scale,
+			(None, None) => 0.0,
+		};
+		let size = TypedVector::new(other.width(), other.height()) * scale;
+		Self::new(self.center() - size / 2.0, size)
+	}
 
 	pub fn combined_with(mut self, other: &Self) -> Self {
 		// TODO: rename to merged_with
@@ -51,12 +182,16 @@ impl Bounds {
 		}
 	}
 
-	pub fn expand(&self, by: Vector) -> Self {
+	/// The same bounds moved by the given offset.
+	pub fn translated(&self, by: TypedVector<S>) -> Self {
+		Self::new(self.start + by, self.size)
+	}
+	pub fn expand(&self, by: TypedVector<S>) -> Self {
 		let start = self.start() - by;
 		let end = self.end() + by;
 		Self::new_with_end(start, end)
 	}
-	pub fn shrink(&self, by: Vector) -> Self {
+	pub fn shrink(&self, by: TypedVector<S>) -> Self {
 		let start = self.start() + by;
 		let end = self.end() - by;
 		Self::new_with_end(start, end)
@@ -67,10 +202,81 @@ impl Bounds {
 		a.try_merge(b);
 		Some(a)
 	}
+
+	/// The same bounds in another space (see [TypedVector::retagged]).
+	pub(crate) fn retagged<T: Space>(&self) -> TypedBounds<T> {
+		TypedBounds::new(self.start.retagged(), self.size.retagged())
+	}
+}
+
+impl<S: Space> From<TypedVector<S>> for TypedBounds<S> {
+	fn from(value: TypedVector<S>) -> Self {
+		Self::new(value, TypedVector::zero())
+	}
 }
 
-impl From<Vector> for Bounds {
-	fn from(value: Vector) -> Self {
-		Self::new(value, Vector::zero())
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::common::Vector;
+
+	fn bounds(x: Number, y: Number, width: Number, height: Number) -> Bounds {
+		Bounds::new(Vector::new(x, y), Vector::new(width, height))
+	}
+
+	#[test]
+	fn normalizes_negative_sizes() {
+		assert_eq!(bounds(10.0, 20.0, -10.0, -5.0).normalized(), bounds(0.0, 15.0, 10.0, 5.0));
+		assert_eq!(bounds(10.0, 20.0, -10.0, 5.0).normalized(), bounds(0.0, 20.0, 10.0, 5.0));
+		assert_eq!(bounds(1.0, 2.0, 3.0, 4.0).normalized(), bounds(1.0, 2.0, 3.0, 4.0));
+	}
+
+	#[test]
+	fn intersects() {
+		let a = bounds(0.0, 0.0, 10.0, 10.0);
+		assert_eq!(a.intersection(&bounds(5.0, 5.0, 10.0, 10.0)), Some(bounds(5.0, 5.0, 5.0, 5.0)));
+		// negative sizes cover the same area
+		assert_eq!(a.intersection(&bounds(15.0, 15.0, -10.0, -10.0)), Some(bounds(5.0, 5.0, 5.0, 5.0)));
+		assert_eq!(a.intersection(&bounds(10.0, 0.0, 5.0, 5.0)), Some(bounds(10.0, 0.0, 0.0, 5.0)));
+		assert_eq!(a.intersection(&bounds(11.0, 0.0, 5.0, 5.0)), None);
+	}
+
+	#[test]
+	fn clamps_bounds() {
+		let area = bounds(0.0, 0.0, 100.0, 50.0);
+		assert_eq!(area.clamp_bounds(&bounds(90.0, -10.0, 20.0, 20.0)), bounds(80.0, 0.0, 20.0, 20.0));
+		assert_eq!(area.clamp_bounds(&bounds(10.0, 10.0, 20.0, 20.0)), bounds(10.0, 10.0, 20.0, 20.0));
+		assert_eq!(area.clamp_bounds(&bounds(110.0, 30.0, -20.0, -20.0)), bounds(80.0, 10.0, 20.0, 20.0));
+		// too large: aligned to the start
+		assert_eq!(area.clamp_bounds(&bounds(50.0, 20.0, 20.0, 80.0)), bounds(50.0, 0.0, 20.0, 80.0));
+	}
+
+	#[test]
+	fn fits_and_fills() {
+		let area = bounds(0.0, 0.0, 100.0, 50.0);
+		let square = bounds(500.0, 500.0, 10.0, 10.0);
+		assert_eq!(area.fit(&square), bounds(25.0, 0.0, 50.0, 50.0));
+		assert_eq!(area.fill(&square), bounds(0.0, -25.0, 100.0, 100.0));
+		assert_eq!(area.fit(&bounds(0.0, 0.0, -20.0, -10.0)), bounds(0.0, 0.0, 100.0, 50.0));
+	}
+
+	#[test]
+	fn fits_and_fills_empty_bounds() {
+		let area = bounds(0.0, 0.0, 100.0, 50.0);
+		let line = bounds(0.0, 0.0, 10.0, 0.0);
+		assert_eq!(area.fit(&line), bounds(0.0, 25.0, 100.0, 0.0));
+		assert_eq!(area.fill(&line), bounds(0.0, 25.0, 100.0, 0.0));
+		let point = bounds(3.0, 4.0, 0.0, 0.0);
+		assert_eq!(area.fit(&point), bounds(50.0, 25.0, 0.0, 0.0));
+		assert_eq!(area.fill(&point), bounds(50.0, 25.0, 0.0, 0.0));
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn serializes_start_and_size() {
+		let bounds = bounds(1.0, 2.0, -3.0, 4.5);
+		let json = serde_json::to_string(&bounds).unwrap();
+		assert_eq!(json, r#"{"start":[1.0,2.0],"size":[-3.0,4.5]}"#);
+		assert_eq!(serde_json::from_str::<Bounds>(&json).unwrap(), bounds);
 	}
 }
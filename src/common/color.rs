@@ -1,27 +1,554 @@
+use std::{fmt::Display, str::FromStr};
+
+use super::{css_colors::named_color, Number};
+use crate::errors::ColorParseError;
+
+/// A color with red, green, blue and alpha channels.
+///
+/// All channels are in the range `0.0..=1.0`.
+/// The color channels are stored straight (not premultiplied with alpha).
+///
+/// Can be parsed from (and serialized to) the common css notations:
+/// - hex: `#rgb`, `#rgba`, `#rrggbb`, `#rrggbbaa`
+/// - functions: `rgb()`, `rgba()`, `hsl()`, `hsla()` (with comma or space separated arguments)
+/// - the css named colors (e.g. `rebeccapurple`) and `transparent`
+///
+/// Displays as hex (`#rrggbb` or `#rrggbbaa` if it's not opaque).
+/// That's also how it's serialized (with the `serde` feature); any of the notations above can be deserialized.
+#[derive(Copy, Clone, PartialEq, Debug)]
 pub struct Color {
-	value: String,
+	pub r: Number,
+	pub g: Number,
+	pub b: Number,
+	pub a: Number,
 }
 
 impl Color {
-	pub fn value(&self) -> &str {
-		self.value.as_str()
+	/// Creates a new color from its channels (each in `0.0..=1.0`).
+	///
+	/// Values outside that range are clamped.
+	pub fn new(r: Number, g: Number, b: Number, a: Number) -> Self {
+		Self { r: clamp_unit(r), g: clamp_unit(g), b: clamp_unit(b), a: clamp_unit(a) }
+	}
+	/// Creates a new opaque color from its channels (each in `0.0..=1.0`).
+	pub fn rgb(r: Number, g: Number, b: Number) -> Self {
+		Self::new(r, g, b, 1.0)
+	}
+	pub fn from_rgba8(r: u8, g: u8, b: u8, a: u8) -> Self {
+		Self::new(r as Number / 255.0, g as Number / 255.0, b as Number / 255.0, a as Number / 255.0)
+	}
+	pub fn from_rgb8(r: u8, g: u8, b: u8) -> Self {
+		Self::from_rgba8(r, g, b, 255)
+	}
+	/// Creates a new opaque color from an integer of the form `0xRRGGBB`.
+	pub fn from_rgb_u32(rgb: u32) -> Self {
+		Self::from_rgb8((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8)
+	}
+
+	pub fn transparent() -> Self {
+		Self::new(0.0, 0.0, 0.0, 0.0)
+	}
+	pub fn black() -> Self {
+		Self::rgb(0.0, 0.0, 0.0)
+	}
+	pub fn white() -> Self {
+		Self::rgb(1.0, 1.0, 1.0)
+	}
+
+	/// The channels as bytes (`[r, g, b, a]`).
+	pub fn to_rgba8(&self) -> [u8; 4] {
+		[to_byte(self.r), to_byte(self.g), to_byte(self.b), to_byte(self.a)]
+	}
+
+	/// This color with its alpha channel replaced.
+	pub fn with_alpha(mut self, alpha: Number) -> Self {
+		self.a = clamp_unit(alpha);
+		self
+	}
+	pub fn is_opaque(&self) -> bool {
+		self.a >= 1.0
+	}
+
+	pub fn from_hsl(hsl: Hsl) -> Self {
+		let Hsl { h, s, l, a } = hsl;
+		let s = clamp_unit(s);
+		let l = clamp_unit(l);
+		let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+		Self::from_hue_chroma(h, chroma, l - chroma / 2.0, a)
+	}
+	pub fn to_hsl(&self) -> Hsl {
+		let (min, max) = self.min_max();
+		let chroma = max - min;
+		let l = (max + min) / 2.0;
+		let s = match l > 0.0 && l < 1.0 {
+			true => chroma / (1.0 - (2.0 * l - 1.0).abs()),
+			false => 0.0,
+		};
+		Hsl { h: self.hue(max, chroma), s, l, a: self.a }
+	}
+
+	pub fn from_hsv(hsv: Hsv) -> Self {
+		let Hsv { h, s, v, a } = hsv;
+		let chroma = clamp_unit(v) * clamp_unit(s);
+		Self::from_hue_chroma(h, chroma, clamp_unit(v) - chroma, a)
+	}
+	pub fn to_hsv(&self) -> Hsv {
+		let (min, max) = self.min_max();
+		let chroma = max - min;
+		let s = match max > 0.0 {
+			true => chroma / max,
+			false => 0.0,
+		};
+		Hsv { h: self.hue(max, chroma), s, v: max, a: self.a }
+	}
+
+	/// The channels premultiplied with alpha (`[r * a, g * a, b * a, a]`).
+	pub fn premultiplied(&self) -> [Number; 4] {
+		[self.r * self.a, self.g * self.a, self.b * self.a, self.a]
+	}
+	/// Creates a color from channels that are premultiplied with alpha.
+	pub fn from_premultiplied(r: Number, g: Number, b: Number, a: Number) -> Self {
+		match a > 0.0 {
+			true => Self::new(r / a, g / a, b / a, a),
+			false => Self::transparent(),
+		}
+	}
+
+	/// Mixes this color with the other one.
+	///
+	/// The mix is done on premultiplied channels, so mixing with a transparent color doesn't darken the result.
+	///
+	/// # Parameters
+	/// - factor: How much of the other color to use (`0.0` is this color, `1.0` is the other one).
+	pub fn mix(&self, other: &Color, factor: Number) -> Self {
+		let factor = clamp_unit(factor);
+		let [r1, g1, b1, a1] = self.premultiplied();
+		let [r2, g2, b2, a2] = other.premultiplied();
+		let lerp = |a: Number, b: Number| a + (b - a) * factor;
+		Self::from_premultiplied(lerp(r1, r2), lerp(g1, g2), lerp(b1, b2), lerp(a1, a2))
+	}
+	/// Composites this color over the background (the Porter-Duff "source over" operator).
+	pub fn over(&self, background: &Color) -> Self {
+		let [r1, g1, b1, a1] = self.premultiplied();
+		let [r2, g2, b2, a2] = background.premultiplied();
+		let rest = 1.0 - a1;
+		Self::from_premultiplied(r1 + r2 * rest, g1 + g2 * rest, b1 + b2 * rest, a1 + a2 * rest)
+	}
+
+	/// Increases the (hsl) lightness by the given amount (`0.0..=1.0`).
+	pub fn lighten(&self, amount: Number) -> Self {
+		let mut hsl = self.to_hsl();
+		hsl.l = clamp_unit(hsl.l + amount);
+		hsl.into()
+	}
+	/// Decreases the (hsl) lightness by the given amount (`0.0..=1.0`).
+	pub fn darken(&self, amount: Number) -> Self {
+		self.lighten(-amount)
+	}
+	/// Increases the (hsl) saturation by the given amount (`0.0..=1.0`).
+	pub fn saturate(&self, amount: Number) -> Self {
+		let mut hsl = self.to_hsl();
+		hsl.s = clamp_unit(hsl.s + amount);
+		hsl.into()
+	}
+	/// Decreases the (hsl) saturation by the given amount (`0.0..=1.0`).
+	pub fn desaturate(&self, amount: Number) -> Self {
+		self.saturate(-amount)
+	}
+	/// The complementary color in rgb space (each channel `c` becomes `1 - c`); alpha is kept.
+	pub fn invert(&self) -> Self {
+		Self::new(1.0 - self.r, 1.0 - self.g, 1.0 - self.b, self.a)
+	}
+
+	/// The relative luminance as defined by WCAG 2 (`0.0` for black to `1.0` for white).
+	///
+	/// Alpha is ignored. To account for it, composite the color onto its background first (see [Color::over]).
+	pub fn relative_luminance(&self) -> Number {
+		let linear = |c: Number| match c <= 0.04045 {
+			true => c / 12.92,
+			false => ((c + 0.055) / 1.055).powf(2.4),
+		};
+		0.2126 * linear(self.r) + 0.7152 * linear(self.g) + 0.0722 * linear(self.b)
+	}
+	/// The contrast ratio with the other color as defined by WCAG 2 (`1.0..=21.0`).
+	///
+	/// WCAG AA requires at least `4.5` for normal text and `3.0` for large text.
+	/// Alpha is ignored (see [Color::relative_luminance]).
+	pub fn contrast_ratio(&self, other: &Color) -> Number {
+		let a = self.relative_luminance();
+		let b = other.relative_luminance();
+		(a.max(b) + 0.05) / (a.min(b) + 0.05)
+	}
+	/// The candidate that has the highest contrast with this color.
+	///
+	/// Returns `None` if there are no candidates.
+	pub fn most_readable(&self, candidates: &[Color]) -> Option<Color> {
+		candidates.iter().copied().max_by(|a, b| self.contrast_ratio(a).total_cmp(&self.contrast_ratio(b)))
+	}
+	/// Black or white, whichever is more readable on this color (e.g. for text on a background of this color).
+	pub fn readable_text_color(&self) -> Self {
+		match self.contrast_ratio(&Self::black()) >= self.contrast_ratio(&Self::white()) {
+			true => Self::black(),
+			false => Self::white(),
+		}
+	}
+
+	/// Parses a css color string.
+	///
+	/// See [Color] for the supported notations.
+	pub fn parse(value: &str) -> Result<Self, ColorParseError> {
+		let value = value.trim().to_ascii_lowercase();
+		if value.is_empty() {
+			return Err(ColorParseError::Empty);
+		}
+		if let Some(hex) = value.strip_prefix('#') {
+			return Self::parse_hex(hex).ok_or(ColorParseError::InvalidHex(value.clone()));
+		}
+		if value.contains('(') {
+			return Self::parse_function(&value);
+		}
+		if value == "transparent" {
+			return Ok(Self::transparent());
+		}
+		named_color(&value).map(Self::from_rgb_u32).ok_or(ColorParseError::UnknownName(value))
+	}
+
+	/// The color in hex notation.
+	///
+	/// That is `#rrggbb` for opaque colors and `#rrggbbaa` otherwise.
+	pub fn to_hex(&self) -> String {
+		let [r, g, b, a] = self.to_rgba8();
+		match self.is_opaque() {
+			true => format!("#{:02x}{:02x}{:02x}", r, g, b),
+			false => format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, a),
+		}
+	}
+	/// The color in `rgb(r, g, b)` notation (or `rgba(r, g, b, a)` if it's not opaque).
+	pub fn to_rgb_string(&self) -> String {
+		let [r, g, b, _] = self.to_rgba8();
+		match self.is_opaque() {
+			true => format!("rgb({}, {}, {})", r, g, b),
+			false => format!("rgba({}, {}, {}, {})", r, g, b, round_to(self.a, 3)),
+		}
+	}
+	/// The color in `hsl(h, s%, l%)` notation (or `hsla(h, s%, l%, a)` if it's not opaque).
+	pub fn to_hsl_string(&self) -> String {
+		let Hsl { h, s, l, a } = self.to_hsl();
+		let (h, s, l) = (round_to(h, 2), round_to(s * 100.0, 2), round_to(l * 100.0, 2));
+		match self.is_opaque() {
+			true => format!("hsl({}, {}%, {}%)", h, s, l),
+			false => format!("hsla({}, {}%, {}%, {})", h, s, l, round_to(a, 3)),
+		}
+	}
+
+	fn min_max(&self) -> (Number, Number) {
+		(self.r.min(self.g).min(self.b), self.r.max(self.g).max(self.b))
+	}
+	/// The hue in degrees, given the maximum channel and the chroma.
+	fn hue(&self, max: Number, chroma: Number) -> Number {
+		if chroma <= 0.0 {
+			return 0.0;
+		}
+		let sector = if max == self.r {
+			((self.g - self.b) / chroma).rem_euclid(6.0)
+		} else if max == self.g {
+			(self.b - self.r) / chroma + 2.0
+		} else {
+			(self.r - self.g) / chroma + 4.0
+		};
+		sector * 60.0
+	}
+	fn from_hue_chroma(hue: Number, chroma: Number, offset: Number, alpha: Number) -> Self {
+		let sector = hue.rem_euclid(360.0) / 60.0;
+		let x = chroma * (1.0 - (sector.rem_euclid(2.0) - 1.0).abs());
+		let (r, g, b) = match sector as u8 {
+			0 => (chroma, x, 0.0),
+			1 => (x, chroma, 0.0),
+			2 => (0.0, chroma, x),
+			3 => (0.0, x, chroma),
+			4 => (x, 0.0, chroma),
+			_ => (chroma, 0.0, x),
+		};
+		Self::new(r + offset, g + offset, b + offset, alpha)
+	}
+
+	fn parse_hex(hex: &str) -> Option<Self> {
+		if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+			return None;
+		}
+		let digit = |i: usize| u8::from_str_radix(&hex[i..i + 1], 16).ok();
+		let byte = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+		match hex.len() {
+			3 => Some(Self::from_rgb8(digit(0)? * 17, digit(1)? * 17, digit(2)? * 17)),
+			4 => Some(Self::from_rgba8(digit(0)? * 17, digit(1)? * 17, digit(2)? * 17, digit(3)? * 17)),
+			6 => Some(Self::from_rgb8(byte(0)?, byte(2)?, byte(4)?)),
+			8 => Some(Self::from_rgba8(byte(0)?, byte(2)?, byte(4)?, byte(6)?)),
+			_ => None,
+		}
+	}
+
+	fn parse_function(value: &str) -> Result<Self, ColorParseError> {
+		let invalid = || ColorParseError::InvalidFunction(value.to_owned());
+
+		let (name, arguments) = value.split_once('(').ok_or_else(invalid)?;
+		let arguments = arguments.strip_suffix(')').ok_or_else(invalid)?;
+		let (channels, alpha) = split_arguments(arguments).ok_or_else(invalid)?;
+		let alpha = match alpha {
+			Some(alpha) => parse_alpha(alpha).ok_or_else(invalid)?,
+			None => 1.0,
+		};
+
+		match name.trim() {
+			"rgb" | "rgba" => {
+				let channel = |i: usize| parse_rgb_channel(channels[i]).ok_or_else(invalid);
+				Ok(Self::new(channel(0)?, channel(1)?, channel(2)?, alpha))
+			},
+			"hsl" | "hsla" => {
+				let h = parse_hue(channels[0]).ok_or_else(invalid)?;
+				let s = parse_percentage(channels[1]).ok_or_else(invalid)?;
+				let l = parse_percentage(channels[2]).ok_or_else(invalid)?;
+				Ok(Self::from_hsl(Hsl { h, s, l, a: alpha }))
+			},
+			name => Err(ColorParseError::UnknownFunction(name.to_owned())),
+		}
 	}
 }
 
-impl From<&'static str> for Color {
-	fn from(value: &'static str) -> Self {
-		Self { value: value.to_owned() }
+/// A color in the HSL (hue, saturation, lightness) color model.
+///
+/// - h: the hue in degrees (`0.0..360.0`)
+/// - s, l, a: in `0.0..=1.0`
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hsl {
+	pub h: Number,
+	pub s: Number,
+	pub l: Number,
+	pub a: Number,
+}
+
+/// A color in the HSV (hue, saturation, value) color model.
+///
+/// - h: the hue in degrees (`0.0..360.0`)
+/// - s, v, a: in `0.0..=1.0`
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hsv {
+	pub h: Number,
+	pub s: Number,
+	pub v: Number,
+	pub a: Number,
+}
+
+impl From<Hsl> for Color {
+	fn from(value: Hsl) -> Self {
+		Self::from_hsl(value)
 	}
 }
 
-impl From<String> for Color {
-	fn from(value: String) -> Self {
-		Self { value }
+impl From<Color> for Hsl {
+	fn from(value: Color) -> Self {
+		value.to_hsl()
+	}
+}
+
+impl From<Hsv> for Color {
+	fn from(value: Hsv) -> Self {
+		Self::from_hsv(value)
+	}
+}
+
+impl From<Color> for Hsv {
+	fn from(value: Color) -> Self {
+		value.to_hsv()
+	}
+}
+
+impl Display for Color {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}", self.to_hex())
+	}
+}
+
+impl FromStr for Color {
+	type Err = ColorParseError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Self::parse(s)
+	}
+}
+
+impl TryFrom<&str> for Color {
+	type Error = ColorParseError;
+
+	fn try_from(value: &str) -> Result<Self, Self::Error> {
+		Self::parse(value)
+	}
+}
+
+impl TryFrom<String> for Color {
+	type Error = ColorParseError;
+
+	fn try_from(value: String) -> Result<Self, Self::Error> {
+		Self::parse(&value)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Color {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.serialize_str(&self.to_hex())
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Color {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let value = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+		Self::parse(&value).map_err(serde::de::Error::custom)
 	}
 }
 
 impl From<Color> for String {
 	fn from(value: Color) -> Self {
-		value.value
+		value.to_hex()
+	}
+}
+
+fn clamp_unit(value: Number) -> Number {
+	match value.is_nan() {
+		true => 0.0,
+		false => value.clamp(0.0, 1.0),
+	}
+}
+
+fn to_byte(value: Number) -> u8 {
+	(clamp_unit(value) * 255.0).round() as u8
+}
+
+fn round_to(value: Number, decimals: i32) -> Number {
+	let factor = (10.0 as Number).powi(decimals);
+	(value * factor).round() / factor
+}
+
+/// Splits the arguments of a color function into its 3 channels and the optional alpha.
+///
+/// Supports both the legacy (`1, 2, 3, 0.5`) and the modern (`1 2 3 / 0.5`) syntax.
+fn split_arguments(arguments: &str) -> Option<(Vec<&str>, Option<&str>)> {
+	let (mut channels, alpha) = match arguments.contains(',') {
+		true => (arguments.split(',').map(str::trim).collect::<Vec<_>>(), None),
+		false => {
+			let (channels, alpha) = match arguments.split_once('/') {
+				Some((channels, alpha)) => (channels, Some(alpha.trim())),
+				None => (arguments, None),
+			};
+			(channels.split_whitespace().collect::<Vec<_>>(), alpha)
+		},
+	};
+	let alpha = match (channels.len(), alpha) {
+		(3, alpha) => alpha,
+		(4, None) => channels.pop(),
+		_ => return None,
+	};
+	Some((channels, alpha))
+}
+
+fn parse_number(value: &str) -> Option<Number> {
+	value.trim().parse::<Number>().ok().filter(|n| n.is_finite())
+}
+
+/// Parses a percentage (`50%`) or a plain number in percent (`50`) into `0.0..=1.0`.
+fn parse_percentage(value: &str) -> Option<Number> {
+	let value = value.strip_suffix('%').unwrap_or(value);
+	Some(clamp_unit(parse_number(value)? / 100.0))
+}
+
+/// Parses an rgb channel (`0..=255` or a percentage) into `0.0..=1.0`.
+fn parse_rgb_channel(value: &str) -> Option<Number> {
+	match value.strip_suffix('%') {
+		Some(percentage) => Some(clamp_unit(parse_number(percentage)? / 100.0)),
+		None => Some(clamp_unit(parse_number(value)? / 255.0)),
+	}
+}
+
+/// Parses an alpha value (`0.0..=1.0` or a percentage) into `0.0..=1.0`.
+fn parse_alpha(value: &str) -> Option<Number> {
+	match value.strip_suffix('%') {
+		Some(percentage) => Some(clamp_unit(parse_number(percentage)? / 100.0)),
+		None => Some(clamp_unit(parse_number(value)?)),
+	}
+}
+
+/// Parses a css hue (`deg` if no unit is given) into degrees.
+fn parse_hue(value: &str) -> Option<Number> {
+	let units: [(&str, Number); 4] =
+		[("deg", 1.0), ("grad", 0.9), ("rad", 180.0 / std::f64::consts::PI), ("turn", 360.0)];
+	for (unit, factor) in units {
+		if let Some(number) = value.strip_suffix(unit) {
+			return Some((parse_number(number)? * factor).rem_euclid(360.0));
+		}
+	}
+	Some(parse_number(value)?.rem_euclid(360.0))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parses_css_notations() {
+		let rebeccapurple = Color::from_rgb8(0x66, 0x33, 0x99);
+		assert_eq!(Color::parse("#639"), Ok(rebeccapurple));
+		assert_eq!(Color::parse("#663399"), Ok(rebeccapurple));
+		assert_eq!(Color::parse(" RebeccaPurple "), Ok(rebeccapurple));
+		assert_eq!(Color::parse("rgb(102, 51, 153)"), Ok(rebeccapurple));
+		assert_eq!(Color::parse("rgb(102 51 153 / 50%)"), Ok(rebeccapurple.with_alpha(0.5)));
+		assert_eq!(Color::parse("rgba(102, 51, 153, 0.5)"), Ok(rebeccapurple.with_alpha(0.5)));
+		assert_eq!(Color::parse("#66339980").map(|c| c.to_rgba8()), Ok([0x66, 0x33, 0x99, 0x80]));
+		assert_eq!(Color::parse("hsl(270, 50%, 40%)").map(|c| c.to_hex()), Ok("#663399".to_owned()));
+		assert_eq!(Color::parse("hsla(0.75turn 50% 40% / 1)").map(|c| c.to_hex()), Ok("#663399".to_owned()));
+		assert_eq!(Color::parse("transparent"), Ok(Color::transparent()));
+	}
+
+	#[test]
+	fn rejects_invalid_colors() {
+		assert_eq!(Color::parse(""), Err(ColorParseError::Empty));
+		assert_eq!(Color::parse("#12"), Err(ColorParseError::InvalidHex("#12".to_owned())));
+		assert_eq!(Color::parse("#ggg"), Err(ColorParseError::InvalidHex("#ggg".to_owned())));
+		assert_eq!(Color::parse("rgb(1, 2)"), Err(ColorParseError::InvalidFunction("rgb(1, 2)".to_owned())));
+		assert_eq!(Color::parse("lab(1 2 3)"), Err(ColorParseError::UnknownFunction("lab".to_owned())));
+		assert_eq!(Color::parse("blurple"), Err(ColorParseError::UnknownName("blurple".to_owned())));
+	}
+
+	#[test]
+	fn converts_between_color_models() {
+		let color = Color::from_rgb8(0x66, 0x33, 0x99);
+		assert_eq!(Color::from(color.to_hsl()).to_rgba8(), color.to_rgba8());
+		assert_eq!(Color::from(color.to_hsv()).to_rgba8(), color.to_rgba8());
+		assert_eq!(color.to_rgb_string(), "rgb(102, 51, 153)");
+		assert_eq!(color.with_alpha(0.5).to_rgb_string(), "rgba(102, 51, 153, 0.5)");
+		assert_eq!(color.to_hsl_string(), "hsl(270, 50%, 40%)");
+	}
+
+	#[test]
+	fn computes_contrast() {
+		assert_eq!(Color::black().contrast_ratio(&Color::white()), 21.0);
+		assert_eq!(Color::white().contrast_ratio(&Color::white()), 1.0);
+		assert_eq!(Color::from_rgb8(0x66, 0x33, 0x99).readable_text_color(), Color::white());
+		assert_eq!(Color::parse("yellow").map(|c| c.readable_text_color()), Ok(Color::black()));
+		assert_eq!(Color::black().mix(&Color::white(), 0.5).to_hex(), "#808080");
+		assert_eq!(Color::black().mix(&Color::transparent(), 0.5).to_hex(), "#00000080");
+		assert_eq!(Color::white().with_alpha(0.5).over(&Color::black()).to_hex(), "#808080");
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn serializes_as_css_string() {
+		let color = Color::from_rgb_u32(0x3b82f6);
+		assert_eq!(serde_json::to_string(&color).unwrap(), "\"#3b82f6\"");
+		assert_eq!(serde_json::to_string(&Color::black().with_alpha(0.5)).unwrap(), "\"#00000080\"");
+		assert_eq!(serde_json::from_str::<Color>("\"#3b82f6\"").unwrap(), color);
+		assert_eq!(serde_json::from_str::<Color>(r#""rgb(59, 130, 246)""#).unwrap(), color);
+		assert!(serde_json::from_str::<Color>(r#""not a color""#).is_err());
 	}
 }
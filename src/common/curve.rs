@@ -0,0 +1,232 @@
+use super::{Bounds, Number, Polyline, Vector};
+
+/// The tolerance used by the [Curve] methods that need to approximate the curve (e.g. [Curve::length]).
+///
+/// The approximation deviates from the actual curve by at most this distance.
+pub const CURVE_TOLERANCE: Number = 0.01;
+
+/// A parametric curve from [Curve::start] (`t = 0`) to [Curve::end] (`t = 1`).
+///
+/// Implementors only need to provide evaluation, bounds and flattening.
+/// Everything that can be computed from the flattened curve (length, arc-length parametrization, closest point, ..) has a default implementation.
+pub trait Curve {
+	/// The point at parameter `t` (`0.0..=1.0`; values outside that range are clamped).
+	fn point_at(&self, t: Number) -> Vector;
+	/// The tight bounds of the curve (normalized).
+	fn bounds(&self) -> Bounds;
+	/// Approximates the curve by points (and their parameters `t`).
+	///
+	/// The straight lines between the points deviate from the curve by at most `tolerance`.
+	/// The first point is at `t = 0` and the last one at `t = 1`.
+	fn flatten_parametric(&self, tolerance: Number) -> Vec<(Number, Vector)>;
+	/// Splits the curve into the parts before and after parameter `t`.
+	fn split_at(&self, t: Number) -> (Self, Self)
+	where
+		Self: Sized;
+
+	fn start(&self) -> Vector {
+		self.point_at(0.0)
+	}
+	fn end(&self) -> Vector {
+		self.point_at(1.0)
+	}
+
+	/// Approximates the curve by a [Polyline] (see [Curve::flatten_parametric]).
+	fn flatten(&self, tolerance: Number) -> Polyline {
+		Polyline::new(self.flatten_parametric(tolerance).into_iter().map(|(_, point)| point).collect())
+	}
+	/// A table to convert between the parameter `t` and the distance along the curve.
+	fn arc_lengths(&self, tolerance: Number) -> ArcLengths {
+		ArcLengths::new(self.flatten_parametric(tolerance))
+	}
+	/// The length of the curve (approximated with [CURVE_TOLERANCE]).
+	fn length(&self) -> Number {
+		self.arc_lengths(CURVE_TOLERANCE).total()
+	}
+	/// The point at the given distance along the curve (approximated with [CURVE_TOLERANCE]).
+	fn point_at_length(&self, length: Number) -> Vector {
+		self.point_at(self.arc_lengths(CURVE_TOLERANCE).t_at(length))
+	}
+
+	/// The point on the curve that is closest to the given point (approximated with [CURVE_TOLERANCE]).
+	fn closest_point(&self, point: Vector) -> CurvePoint {
+		let samples = self.flatten_parametric(CURVE_TOLERANCE);
+		let mut closest = (0.0, Number::INFINITY);
+		for pair in samples.windows(2) {
+			let [(t0, p0), (t1, p1)] = [pair[0], pair[1]];
+			let u = Line::new(p0, p1).project(point);
+			let distance = (p0 + (p1 - p0) * u - point).length();
+			if distance < closest.1 {
+				closest = (t0 + (t1 - t0) * u, distance);
+			}
+		}
+		if let [(t, p)] = samples.as_slice() {
+			closest = (*t, (*p - point).length());
+		}
+		let t = closest.0;
+		let on_curve = self.point_at(t);
+		CurvePoint { t, point: on_curve, distance: (on_curve - point).length() }
+	}
+	/// The distance from the point to the curve (approximated with [CURVE_TOLERANCE]).
+	fn distance_to(&self, point: Vector) -> Number {
+		self.closest_point(point).distance
+	}
+}
+
+/// A point on a [Curve] (see [Curve::closest_point]).
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct CurvePoint {
+	/// The parameter of the point.
+	pub t: Number,
+	pub point: Vector,
+	/// The distance to the point that was queried.
+	pub distance: Number,
+}
+
+/// The cumulative lengths along a flattened [Curve] (see [Curve::arc_lengths]).
+///
+/// Used for arc-length parametrization, i.e. to move along a curve at constant speed.
+pub struct ArcLengths {
+	/// (t, length from the start up to t)
+	samples: Vec<(Number, Number)>,
+}
+
+impl ArcLengths {
+	/// Creates the table from points of the curve (and their parameters).
+	pub fn new(points: Vec<(Number, Vector)>) -> Self {
+		let mut length = 0.0;
+		let mut samples = Vec::with_capacity(points.len());
+		for (i, (t, point)) in points.iter().enumerate() {
+			if i > 0 {
+				length += (*point - points[i - 1].1).length();
+			}
+			samples.push((*t, length));
+		}
+		Self { samples }
+	}
+
+	/// The length of the whole curve.
+	pub fn total(&self) -> Number {
+		self.samples.last().map(|(_, length)| *length).unwrap_or(0.0)
+	}
+	/// The parameter `t` at the given distance from the start.
+	pub fn t_at(&self, length: Number) -> Number {
+		Self::interpolate(&self.samples, length, |(_, l)| *l, |(t, _)| *t)
+	}
+	/// The distance from the start at parameter `t`.
+	pub fn length_at(&self, t: Number) -> Number {
+		Self::interpolate(&self.samples, t, |(t, _)| *t, |(_, l)| *l)
+	}
+	/// The parameter `t` at the given fraction (`0.0..=1.0`) of the total length.
+	pub fn t_at_fraction(&self, fraction: Number) -> Number {
+		self.t_at(fraction * self.total())
+	}
+
+	fn interpolate(
+		samples: &[(Number, Number)],
+		value: Number,
+		key: impl Fn(&(Number, Number)) -> Number,
+		result: impl Fn(&(Number, Number)) -> Number,
+	) -> Number {
+		let (Some(first), Some(last)) = (samples.first(), samples.last()) else { return 0.0 };
+		if value <= key(first) {
+			return result(first);
+		}
+		if value >= key(last) {
+			return result(last);
+		}
+		let i = samples.partition_point(|sample| key(sample) < value);
+		let (a, b) = (&samples[i - 1], &samples[i]);
+		let span = key(b) - key(a);
+		let u = match span > 0.0 {
+			true => (value - key(a)) / span,
+			false => 0.0,
+		};
+		result(a) + (result(b) - result(a)) * u
+	}
+}
+
+/// A straight line segment.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Line {
+	pub start: Vector,
+	pub end: Vector,
+}
+
+impl Line {
+	pub fn new(start: Vector, end: Vector) -> Self {
+		Self { start, end }
+	}
+
+	/// The parameter (`0.0..=1.0`) of the point on the line that is closest to the given point.
+	pub fn project(&self, point: Vector) -> Number {
+		let direction = self.end - self.start;
+		let length_squared = direction.dot(&direction);
+		match length_squared > 0.0 {
+			true => ((point - self.start).dot(&direction) / length_squared).clamp(0.0, 1.0),
+			false => 0.0,
+		}
+	}
+}
+
+impl Curve for Line {
+	fn point_at(&self, t: Number) -> Vector {
+		self.start + (self.end - self.start) * t.clamp(0.0, 1.0)
+	}
+	fn bounds(&self) -> Bounds {
+		Bounds::new_with_end(self.start, self.end).normalized()
+	}
+	fn flatten_parametric(&self, _tolerance: Number) -> Vec<(Number, Vector)> {
+		vec![(0.0, self.start), (1.0, self.end)]
+	}
+	fn split_at(&self, t: Number) -> (Self, Self) {
+		let middle = self.point_at(t);
+		(Self::new(self.start, middle), Self::new(middle, self.end))
+	}
+
+	fn start(&self) -> Vector {
+		self.start
+	}
+	fn end(&self) -> Vector {
+		self.end
+	}
+	fn length(&self) -> Number {
+		(self.end - self.start).length()
+	}
+	fn closest_point(&self, point: Vector) -> CurvePoint {
+		let t = self.project(point);
+		let on_line = self.point_at(t);
+		CurvePoint { t, point: on_line, distance: (on_line - point).length() }
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn line_points_and_projection() {
+		let line = Line::new(Vector::new(0.0, 0.0), Vector::new(4.0, 2.0));
+		assert_eq!(line.point_at(0.25), Vector::new(1.0, 0.5));
+		assert_eq!(line.point_at(2.0), line.end);
+		assert_eq!(line.length(), Number::sqrt(20.0));
+
+		let closest = line.closest_point(Vector::new(0.0, 5.0));
+		assert_eq!(closest.t, 0.5);
+		assert_eq!(closest.point, Vector::new(2.0, 1.0));
+		assert_eq!(line.distance_to(Vector::new(-3.0, -4.0)), 5.0);
+	}
+
+	#[test]
+	fn arc_lengths_interpolate() {
+		let points = [(0.0, Vector::new(0.0, 0.0)), (0.5, Vector::new(3.0, 0.0)), (1.0, Vector::new(3.0, 1.0))];
+		let lengths = ArcLengths::new(points.to_vec());
+		assert_eq!(lengths.total(), 4.0);
+		assert_eq!(lengths.length_at(0.25), 1.5);
+		assert_eq!(lengths.t_at(3.5), 0.75);
+		assert_eq!(lengths.t_at_fraction(0.75), 0.5);
+		assert_eq!(lengths.t_at(10.0), 1.0);
+		assert_eq!(ArcLengths::new(Vec::new()).t_at(1.0), 0.0);
+	}
+}
@@ -1,4 +1,18 @@
 //! Common basic data structures.
+//!
+//! # Coordinate spaces
+//! [Vector] and [Bounds] don't know which coordinate space they are in (they are in the [Unspecified] space).
+//! Where mixing up spaces is a risk, use them with a [Space] (e.g. [ScreenVector], [WorldBounds]) instead:
+//! they can only be combined with coordinates in the same space and only converted by a [SpaceTransform].
+//!
+//! # Serialization
+//! With the `serde` feature the data types in here implement `Serialize` and `Deserialize`.
+//! The encodings are stable:
+//! - [Vector]: `[x, y]`
+//! - [Bounds]: `{ "start": [x, y], "size": [x, y] }`
+//! - [Color]: a css string (serialized as `#rrggbb` or `#rrggbbaa`; any css notation [Color::parse] supports is accepted)
+//! - [Transform]: `{ "a": .., "b": .., "c": .., "d": .., "e": .., "f": .. }` (see [Transform] for their meaning)
+//! - curves ([Line], [QuadraticBezier], [CubicBezier], [Polyline], [Path]): objects with their points as fields
 
 mod number;
 pub use number::*;
@@ -6,8 +20,34 @@ pub use number::*;
 mod color;
 pub use color::*;
 
+mod css_colors;
+
 mod vector;
 pub use vector::*;
 
 mod bounds;
 pub use bounds::*;
+
+mod transform;
+pub use transform::*;
+
+mod space;
+pub use space::*;
+
+mod spatial_index;
+pub use spatial_index::*;
+
+mod curve;
+pub use curve::*;
+
+mod polyline;
+pub use polyline::*;
+
+mod bezier;
+pub use bezier::*;
+
+mod path;
+pub use path::*;
+
+mod snapping;
+pub use snapping::*;
@@ -0,0 +1,249 @@
+use super::{Bounds, CubicBezier, Curve, Line, Number, QuadraticBezier, Vector};
+
+/// One part of a [Path].
+///
+/// Serialized (with the `serde` feature) as the segment with an additional `"type"` (`"line"`, `"quadratic"` or `"cubic"`),
+/// e.g. `{ "type": "line", "start": [x, y], "end": [x, y] }`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "snake_case"))]
+pub enum Segment {
+	Line(Line),
+	Quadratic(QuadraticBezier),
+	Cubic(CubicBezier),
+}
+
+impl Segment {
+	fn curve(&self) -> &dyn Curve {
+		match self {
+			Segment::Line(line) => line,
+			Segment::Quadratic(quadratic) => quadratic,
+			Segment::Cubic(cubic) => cubic,
+		}
+	}
+}
+
+impl Curve for Segment {
+	fn point_at(&self, t: Number) -> Vector {
+		self.curve().point_at(t)
+	}
+	fn bounds(&self) -> Bounds {
+		self.curve().bounds()
+	}
+	fn flatten_parametric(&self, tolerance: Number) -> Vec<(Number, Vector)> {
+		self.curve().flatten_parametric(tolerance)
+	}
+	fn split_at(&self, t: Number) -> (Self, Self) {
+		match self {
+			Segment::Line(line) => {
+				let (a, b) = line.split_at(t);
+				(a.into(), b.into())
+			},
+			Segment::Quadratic(quadratic) => {
+				let (a, b) = quadratic.split_at(t);
+				(a.into(), b.into())
+			},
+			Segment::Cubic(cubic) => {
+				let (a, b) = cubic.split_at(t);
+				(a.into(), b.into())
+			},
+		}
+	}
+
+	fn start(&self) -> Vector {
+		self.curve().start()
+	}
+	fn end(&self) -> Vector {
+		self.curve().end()
+	}
+}
+
+impl From<Line> for Segment {
+	fn from(value: Line) -> Self {
+		Self::Line(value)
+	}
+}
+
+impl From<QuadraticBezier> for Segment {
+	fn from(value: QuadraticBezier) -> Self {
+		Self::Quadratic(value)
+	}
+}
+
+impl From<CubicBezier> for Segment {
+	fn from(value: CubicBezier) -> Self {
+		Self::Cubic(value)
+	}
+}
+
+/// A sequence of connected [Segment]s (like an svg path without jumps).
+///
+/// As a [Curve], the parameter `t` is distributed evenly over the segments (not by length):
+/// with `n` segments, segment `i` covers `t` in `i/n..=(i+1)/n`.
+/// Use [Curve::arc_lengths] to move along the path by distance.
+///
+/// # Example
+/// ```text
+/// let path = Path::new(Vector::zero())
+///     .line_to(Vector::new(10.0, 0.0))
+///     .quadratic_to(Vector::new(20.0, 0.0), Vector::new(20.0, 10.0))
+///     .close();
+/// ```
+///
+/// Serialized (with the `serde` feature) as `{ "start": [x, y], "segments": [..] }`.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Path {
+	start: Vector,
+	segments: Vec<Segment>,
+}
+
+impl Path {
+	/// Creates an empty path that starts at the given point.
+	pub fn new(start: Vector) -> Self {
+		Self { start, segments: Vec::new() }
+	}
+
+	pub fn segments(&self) -> &[Segment] {
+		&self.segments
+	}
+	/// The point where the next segment starts (the end of the last one).
+	pub fn current_point(&self) -> Vector {
+		self.segments.last().map(|segment| segment.end()).unwrap_or(self.start)
+	}
+
+	/// Adds a segment.
+	///
+	/// The segment should start at [Path::current_point]; that's not checked.
+	pub fn push(&mut self, segment: impl Into<Segment>) {
+		self.segments.push(segment.into());
+	}
+	pub fn line_to(mut self, end: Vector) -> Self {
+		self.push(Line::new(self.current_point(), end));
+		self
+	}
+	pub fn quadratic_to(mut self, control: Vector, end: Vector) -> Self {
+		self.push(QuadraticBezier::new(self.current_point(), control, end));
+		self
+	}
+	pub fn cubic_to(mut self, control1: Vector, control2: Vector, end: Vector) -> Self {
+		self.push(CubicBezier::new(self.current_point(), control1, control2, end));
+		self
+	}
+	/// Adds a line back to the start (if the path doesn't end there already).
+	pub fn close(self) -> Self {
+		match self.current_point() == self.start {
+			true => self,
+			false => {
+				let start = self.start;
+				self.line_to(start)
+			},
+		}
+	}
+
+	/// The index of the segment at parameter `t` and the parameter on that segment.
+	fn locate(&self, t: Number) -> (usize, Number) {
+		let count = self.segments.len();
+		if count == 0 {
+			return (0, 0.0);
+		}
+		let scaled = t.clamp(0.0, 1.0) * count as Number;
+		let index = (scaled.floor() as usize).min(count - 1);
+		(index, scaled - index as Number)
+	}
+}
+
+impl Curve for Path {
+	fn point_at(&self, t: Number) -> Vector {
+		let (index, local) = self.locate(t);
+		match self.segments.get(index) {
+			Some(segment) => segment.point_at(local),
+			None => self.start,
+		}
+	}
+	fn bounds(&self) -> Bounds {
+		self
+			.segments
+			.iter()
+			.fold(Bounds::from(self.start), |bounds, segment| bounds.combined_with(&segment.bounds()))
+	}
+	fn flatten_parametric(&self, tolerance: Number) -> Vec<(Number, Vector)> {
+		let count = self.segments.len() as Number;
+		let mut points = vec![(0.0, self.start)];
+		for (i, segment) in self.segments.iter().enumerate() {
+			let samples = segment.flatten_parametric(tolerance);
+			// the first point of each segment is the last point of the previous one
+			points.extend(samples.into_iter().skip(1).map(|(t, point)| ((i as Number + t) / count, point)));
+		}
+		points
+	}
+	fn split_at(&self, t: Number) -> (Self, Self) {
+		let (index, local) = self.locate(t);
+		let Some(segment) = self.segments.get(index) else { return (self.clone(), self.clone()) };
+		let (a, b) = segment.split_at(local);
+
+		let mut first = Self { start: self.start, segments: self.segments[..index].to_vec() };
+		first.push(a);
+		let mut second = Self::new(b.start());
+		second.push(b);
+		second.segments.extend_from_slice(&self.segments[index + 1..]);
+		(first, second)
+	}
+
+	fn start(&self) -> Vector {
+		self.start
+	}
+	fn end(&self) -> Vector {
+		self.current_point()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// A line to the right and then an arch back to the start.
+	fn path() -> Path {
+		Path::new(Vector::new(0.0, 0.0))
+			.line_to(Vector::new(2.0, 0.0))
+			.cubic_to(Vector::new(2.0, 2.0), Vector::new(0.0, 2.0), Vector::new(0.0, 0.0))
+	}
+
+	#[test]
+	fn locates_segments() {
+		let path = path();
+		assert_eq!(path.point_at(0.25), Vector::new(1.0, 0.0));
+		assert_eq!(path.point_at(0.75), Vector::new(1.0, 1.5));
+		assert_eq!(path.end(), Vector::new(0.0, 0.0));
+		assert_eq!(path.clone().close().segments().len(), 2);
+		assert_eq!(Path::new(Vector::new(1.0, 1.0)).point_at(0.5), Vector::new(1.0, 1.0));
+	}
+
+	#[test]
+	fn splits_continuously() {
+		let path = path();
+		let (first, second) = path.split_at(0.75);
+		assert_eq!(first.segments().len(), 2);
+		assert_eq!(second.segments().len(), 1);
+		assert_eq!(first.end(), second.start());
+		assert_eq!(first.end(), path.point_at(0.75));
+		assert!((first.point_at(0.5) - path.point_at(0.5)).length() < 1e-9);
+	}
+
+	#[test]
+	fn measures() {
+		let path = path();
+		// the arch reaches up to 0.75 of its control points
+		assert_eq!(path.bounds(), Bounds::new(Vector::zero(), Vector::new(2.0, 1.5)));
+
+		let square = Path::new(Vector::zero())
+			.line_to(Vector::new(1.0, 0.0))
+			.line_to(Vector::new(1.0, 1.0))
+			.line_to(Vector::new(0.0, 1.0))
+			.close();
+		assert_eq!(square.length(), 4.0);
+		assert_eq!(square.point_at_length(2.5), Vector::new(0.5, 1.0));
+		assert_eq!(square.distance_to(Vector::new(0.5, 0.25)), 0.25);
+		assert_eq!(square.closest_point(Vector::new(2.0, 0.5)).point, Vector::new(1.0, 0.5));
+	}
+}
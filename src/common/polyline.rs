@@ -0,0 +1,119 @@
+use super::{Bounds, Curve, Number, Vector};
+
+/// A sequence of points connected by straight lines.
+///
+/// As a [Curve], the parameter `t` is distributed evenly over the lines (not by length):
+/// with `n` lines, line `i` covers `t` in `i/n..=(i+1)/n`.
+///
+/// An empty polyline behaves like a single point at the origin.
+///
+/// Serialized (with the `serde` feature) as `{ "points": [[x, y], ..] }`.
+#[derive(Clone, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Polyline {
+	pub points: Vec<Vector>,
+}
+
+impl Polyline {
+	pub fn new(points: Vec<Vector>) -> Self {
+		Self { points }
+	}
+
+	/// The number of lines (that is one less than the number of points).
+	pub fn line_count(&self) -> usize {
+		self.points.len().saturating_sub(1)
+	}
+
+	/// The index of the line at parameter `t` and the parameter on that line.
+	fn locate(&self, t: Number) -> (usize, Number) {
+		let lines = self.line_count();
+		if lines == 0 {
+			return (0, 0.0);
+		}
+		let scaled = t.clamp(0.0, 1.0) * lines as Number;
+		let index = (scaled.floor() as usize).min(lines - 1);
+		(index, scaled - index as Number)
+	}
+}
+
+impl Curve for Polyline {
+	fn point_at(&self, t: Number) -> Vector {
+		let (index, local) = self.locate(t);
+		match self.points.get(index..=index + 1) {
+			Some([a, b]) => *a + (*b - *a) * local,
+			_ => self.points.first().copied().unwrap_or(Vector::zero()),
+		}
+	}
+	fn bounds(&self) -> Bounds {
+		let Some((first, rest)) = self.points.split_first() else { return Bounds::from(Vector::zero()) };
+		let (min, max) = rest.iter().fold((*first, *first), |(min, max), point| (min.min(point), max.max(point)));
+		Bounds::new_with_end(min, max)
+	}
+	fn flatten_parametric(&self, _tolerance: Number) -> Vec<(Number, Vector)> {
+		let lines = self.line_count().max(1) as Number;
+		self.points.iter().enumerate().map(|(i, point)| (i as Number / lines, *point)).collect()
+	}
+	fn split_at(&self, t: Number) -> (Self, Self) {
+		if self.points.len() < 2 {
+			return (self.clone(), self.clone());
+		}
+		let (index, _) = self.locate(t);
+		let middle = self.point_at(t);
+		let mut first = self.points[..=index].to_vec();
+		first.push(middle);
+		let mut second = vec![middle];
+		second.extend_from_slice(&self.points[index + 1..]);
+		(Self::new(first), Self::new(second))
+	}
+
+	fn flatten(&self, _tolerance: Number) -> Polyline {
+		self.clone()
+	}
+}
+
+impl From<Vec<Vector>> for Polyline {
+	fn from(value: Vec<Vector>) -> Self {
+		Self::new(value)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn polyline() -> Polyline {
+		Polyline::new(vec![Vector::new(0.0, 0.0), Vector::new(3.0, 0.0), Vector::new(3.0, 4.0)])
+	}
+
+	#[test]
+	fn distributes_t_over_the_lines() {
+		let polyline = polyline();
+		assert_eq!(polyline.point_at(0.25), Vector::new(1.5, 0.0));
+		assert_eq!(polyline.point_at(0.5), Vector::new(3.0, 0.0));
+		assert_eq!(polyline.point_at(0.75), Vector::new(3.0, 2.0));
+		assert_eq!(polyline.point_at(1.0), Vector::new(3.0, 4.0));
+		assert_eq!(Polyline::default().point_at(0.5), Vector::zero());
+	}
+
+	#[test]
+	fn splits_continuously() {
+		let polyline = polyline();
+		let (first, second) = polyline.split_at(0.75);
+		assert_eq!(first.points, vec![Vector::new(0.0, 0.0), Vector::new(3.0, 0.0), Vector::new(3.0, 2.0)]);
+		assert_eq!(second.points, vec![Vector::new(3.0, 2.0), Vector::new(3.0, 4.0)]);
+		assert_eq!(first.length() + second.length(), polyline.length());
+	}
+
+	#[test]
+	fn measures() {
+		let polyline = polyline();
+		assert_eq!(polyline.length(), 7.0);
+		assert_eq!(polyline.point_at_length(5.0), Vector::new(3.0, 2.0));
+		assert_eq!(polyline.bounds(), Bounds::new(Vector::zero(), Vector::new(3.0, 4.0)));
+
+		let closest = polyline.closest_point(Vector::new(5.0, 1.0));
+		assert_eq!(closest.point, Vector::new(3.0, 1.0));
+		assert_eq!(closest.distance, 2.0);
+		assert_eq!(polyline.distance_to(Vector::new(-3.0, 4.0)), 5.0);
+	}
+}
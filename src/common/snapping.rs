@@ -0,0 +1,269 @@
+use super::{Bounds, Number, Vector};
+
+/// Two values closer than this are considered equal when collecting the [SnapLine]s that fired.
+const EPSILON: Number = 1e-9;
+
+/// The axis a [Guide] or [SnapLine] is perpendicular to.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Axis {
+	/// A vertical line (`x = value`).
+	X,
+	/// A horizontal line (`y = value`).
+	Y,
+}
+
+/// A user-defined line to snap to.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Guide {
+	pub axis: Axis,
+	pub value: Number,
+}
+
+impl Guide {
+	/// The vertical line `x = value`.
+	pub fn vertical(x: Number) -> Self {
+		Self { axis: Axis::X, value: x }
+	}
+	/// The horizontal line `y = value`.
+	pub fn horizontal(y: Number) -> Self {
+		Self { axis: Axis::Y, value: y }
+	}
+}
+
+/// What a [SnapLine] came from.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum SnapSource {
+	Grid,
+	/// An edge of one of the target bounds.
+	Edge,
+	/// The center of one of the target bounds.
+	Center,
+	Guide,
+}
+
+/// A line that something snapped to (e.g. to display it while dragging).
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct SnapLine {
+	pub guide: Guide,
+	pub source: SnapSource,
+	/// For lines from target bounds: the range along the line that covers both the target and the snapped item.
+	/// `None` for lines that are infinite (grid and guides).
+	pub extent: Option<(Number, Number)>,
+}
+
+/// The result of snapping a value (see [Snapper]).
+#[derive(Clone, PartialEq, Debug)]
+pub struct SnapResult<T> {
+	/// The snapped value.
+	pub value: T,
+	/// How far the value was moved by snapping.
+	pub offset: Vector,
+	/// The lines the snapped value lies on.
+	pub lines: Vec<SnapLine>,
+}
+
+/// A grid to snap to.
+#[derive(Clone, PartialEq, Debug)]
+pub struct SnapGrid {
+	/// The distance between grid lines (in x and y direction).
+	pub spacing: Vector,
+	/// A point the grid lines go through.
+	pub origin: Vector,
+	pub threshold: Number,
+}
+
+/// Snaps points and bounds to a grid, to the edges and centers of other bounds and to guides.
+///
+/// Each kind of target has its own threshold: targets further away than that are ignored.
+/// The x and y coordinates are snapped independently, each to the closest target in range.
+///
+/// Bounds snap with their edges and their center.
+/// When snapping something that is being dragged, make sure it's not one of the targets itself.
+///
+/// # Example
+/// ```text
+/// let snapper = Snapper::new()
+///     .with_grid(Vector::new_square(10.0), 4.0)
+///     .with_targets(other_items, 6.0)
+///     .with_guides(vec![Guide::vertical(100.0)], 8.0);
+/// let snapped = snapper.snap_bounds(&dragged);
+/// ```
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Snapper {
+	pub grid: Option<SnapGrid>,
+	pub targets: Vec<Bounds>,
+	pub target_threshold: Number,
+	pub guides: Vec<Guide>,
+	pub guide_threshold: Number,
+}
+
+/// A value that something can snap to on one axis.
+struct Candidate {
+	value: Number,
+	threshold: Number,
+	source: SnapSource,
+	/// the bounds this candidate came from (to compute the extent of the snap line)
+	target: Option<Bounds>,
+}
+
+impl Snapper {
+	/// A snapper that doesn't snap to anything (yet).
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Snaps to a grid with the given spacing (going through the origin).
+	pub fn with_grid(mut self, spacing: Vector, threshold: Number) -> Self {
+		self.grid = Some(SnapGrid { spacing, origin: Vector::zero(), threshold });
+		self
+	}
+	/// Snaps to the edges and centers of the given bounds.
+	pub fn with_targets(mut self, targets: Vec<Bounds>, threshold: Number) -> Self {
+		self.targets = targets;
+		self.target_threshold = threshold;
+		self
+	}
+	/// Snaps to the given guides.
+	pub fn with_guides(mut self, guides: Vec<Guide>, threshold: Number) -> Self {
+		self.guides = guides;
+		self.guide_threshold = threshold;
+		self
+	}
+
+	/// Snaps a point.
+	pub fn snap_point(&self, point: Vector) -> SnapResult<Vector> {
+		let SnapResult { offset, lines, .. } = self.snap_bounds(&Bounds::from(point));
+		SnapResult { value: point + offset, offset, lines }
+	}
+	/// Snaps bounds by moving them (they are not resized).
+	pub fn snap_bounds(&self, bounds: &Bounds) -> SnapResult<Bounds> {
+		let bounds = bounds.normalized();
+		let offset = Vector::new(self.snap_axis(&bounds, Axis::X), self.snap_axis(&bounds, Axis::Y));
+		let value = bounds.translated(offset);
+		let mut lines = self.lines_for(&value, Axis::X);
+		lines.extend(self.lines_for(&value, Axis::Y));
+		SnapResult { value, offset, lines }
+	}
+
+	/// The offset along the axis to the closest candidate in range (`0` if there is none).
+	fn snap_axis(&self, bounds: &Bounds, axis: Axis) -> Number {
+		let mut best: Option<Number> = None;
+		for feature in features(bounds, axis) {
+			for candidate in self.candidates(feature, axis) {
+				let delta = candidate.value - feature;
+				if delta.abs() <= candidate.threshold && best.is_none_or(|best| delta.abs() < best.abs()) {
+					best = Some(delta);
+				}
+			}
+		}
+		best.unwrap_or(0.0)
+	}
+
+	/// The lines the (snapped) bounds lie on.
+	fn lines_for(&self, bounds: &Bounds, axis: Axis) -> Vec<SnapLine> {
+		let mut lines: Vec<SnapLine> = Vec::new();
+		for feature in features(bounds, axis) {
+			for candidate in self.candidates(feature, axis) {
+				if (candidate.value - feature).abs() > EPSILON {
+					continue;
+				}
+				let extent = candidate.target.map(|target| {
+					let merged = target.combined_with(bounds);
+					match axis {
+						Axis::X => (merged.top(), merged.bottom()),
+						Axis::Y => (merged.left(), merged.right()),
+					}
+				});
+				let line = SnapLine { guide: Guide { axis, value: candidate.value }, source: candidate.source, extent };
+				if !lines.contains(&line) {
+					lines.push(line);
+				}
+			}
+		}
+		lines
+	}
+
+	/// Everything a feature (edge or center) at `value` could snap to on the axis.
+	fn candidates(&self, value: Number, axis: Axis) -> Vec<Candidate> {
+		let mut candidates = Vec::new();
+		for guide in self.guides.iter().filter(|guide| guide.axis == axis) {
+			candidates.push(Candidate {
+				value: guide.value,
+				threshold: self.guide_threshold,
+				source: SnapSource::Guide,
+				target: None,
+			});
+		}
+		for target in &self.targets {
+			let [min, center, max] = features(target, axis);
+			for (value, source) in [(min, SnapSource::Edge), (center, SnapSource::Center), (max, SnapSource::Edge)] {
+				candidates.push(Candidate {
+					value,
+					threshold: self.target_threshold,
+					source,
+					target: Some(target.normalized()),
+				});
+			}
+		}
+		if let Some(grid) = &self.grid {
+			let (spacing, origin) = match axis {
+				Axis::X => (grid.spacing.x, grid.origin.x),
+				Axis::Y => (grid.spacing.y, grid.origin.y),
+			};
+			if spacing > 0.0 {
+				candidates.push(Candidate {
+					value: origin + ((value - origin) / spacing).round() * spacing,
+					threshold: grid.threshold,
+					source: SnapSource::Grid,
+					target: None,
+				});
+			}
+		}
+		candidates
+	}
+}
+
+/// The coordinates of the edges and the center of the bounds along the axis.
+fn features(bounds: &Bounds, axis: Axis) -> [Number; 3] {
+	match axis {
+		Axis::X => [bounds.left(), bounds.center().x, bounds.right()],
+		Axis::Y => [bounds.top(), bounds.center().y, bounds.bottom()],
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn square(x: Number, y: Number, size: Number) -> Bounds {
+		Bounds::new(Vector::new(x, y), Vector::new_square(size))
+	}
+
+	#[test]
+	fn snaps_bounds_to_targets_and_guides() {
+		let snapper = Snapper::new()
+			.with_targets(vec![square(100.0, 100.0, 20.0)], 5.0)
+			.with_guides(vec![Guide::horizontal(150.0)], 5.0);
+
+		// x: the right edge (96) snaps to the left edge of the target (100); y: the top (153) snaps to the guide (150)
+		let snapped = snapper.snap_bounds(&square(76.0, 153.0, 20.0));
+		assert_eq!(snapped.value, square(80.0, 150.0, 20.0));
+		assert_eq!(snapped.offset, Vector::new(4.0, -3.0));
+		assert_eq!(
+			snapped.lines,
+			vec![
+				SnapLine { guide: Guide::vertical(100.0), source: SnapSource::Edge, extent: Some((100.0, 170.0)) },
+				SnapLine { guide: Guide::horizontal(150.0), source: SnapSource::Guide, extent: None },
+			]
+		);
+	}
+
+	#[test]
+	fn snaps_points_to_grid() {
+		let snapper = Snapper::new().with_grid(Vector::new_square(10.0), 2.0);
+		assert_eq!(snapper.snap_point(Vector::new(31.5, 68.0)).value, Vector::new(30.0, 70.0));
+		assert_eq!(snapper.snap_point(Vector::new(35.0, 65.0)).offset, Vector::zero());
+	}
+}
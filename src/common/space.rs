@@ -0,0 +1,159 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use super::{Transform, TypedBounds, TypedVector};
+
+/// A coordinate space, the parameter of [TypedVector]s and [TypedBounds].
+///
+/// Coordinates in different spaces can't be mixed (e.g. added) and a [SpaceTransform] is the only way to convert between them:
+/// ```
+/// # use webbit::common::{Screen, ScreenVector, SpaceTransform, World, WorldVector};
+/// let screen_to_world = SpaceTransform::<Screen, World>::identity();
+/// let world = screen_to_world.apply(ScreenVector::new(1.0, 2.0)) + WorldVector::new(3.0, 4.0);
+/// ```
+/// ```compile_fail
+/// # use webbit::common::{ScreenVector, WorldVector};
+/// let sum = ScreenVector::new(1.0, 2.0) + WorldVector::new(3.0, 4.0);
+/// ```
+/// ```compile_fail
+/// # use webbit::common::{ScreenVector, WorldVector};
+/// let world: WorldVector = ScreenVector::new(1.0, 2.0);
+/// ```
+/// ```compile_fail
+/// # use webbit::common::{ScreenVector, WorldVector};
+/// let world: WorldVector = ScreenVector::new(1.0, 2.0).retagged();
+/// ```
+pub trait Space: Copy + Clone + PartialEq + Debug + Default + 'static {}
+
+/// The space of coordinates that don't state where they belong: the one of [Vector](super::Vector) and [Bounds](super::Bounds).
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct Unspecified;
+impl Space for Unspecified {}
+
+/// Coordinates on the screen (/in the viewport), e.g. `client_x`/`client_y` of pointer events.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct Screen;
+impl Space for Screen {}
+
+/// Coordinates in the document/scene (/canvas) that is displayed; independent of scrolling and zoom.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct World;
+impl Space for World {}
+
+/// Coordinates relative to an element/object (e.g. the content of a [SelectionFrame](crate::components::SelectionFrame)).
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct Local;
+impl Space for Local {}
+
+pub type ScreenVector = TypedVector<Screen>;
+pub type WorldVector = TypedVector<World>;
+pub type LocalVector = TypedVector<Local>;
+pub type ScreenBounds = TypedBounds<Screen>;
+pub type WorldBounds = TypedBounds<World>;
+pub type LocalBounds = TypedBounds<Local>;
+
+/// A [Transform] from the coordinate space `Source` to the space `Target`.
+///
+/// This is the only way to convert [TypedVector]s and [TypedBounds] between spaces.
+///
+/// # Example
+/// ```text
+/// let screen_to_world = SpaceTransform::<Screen, World>::new(view.inverse().unwrap());
+/// let position: WorldVector = screen_to_world.apply(pointer);
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct SpaceTransform<Source: Space, Target: Space> {
+	transform: Transform,
+	spaces: PhantomData<(Source, Target)>,
+}
+
+impl<Source: Space, Target: Space> SpaceTransform<Source, Target> {
+	/// States that the transformation converts from `Source` to `Target`.
+	pub fn new(transform: Transform) -> Self {
+		Self { transform, spaces: PhantomData }
+	}
+	pub fn identity() -> Self {
+		Self::new(Transform::identity())
+	}
+
+	/// The transformation without its spaces.
+	pub fn transform(&self) -> Transform {
+		self.transform
+	}
+
+	/// Transforms a point (see [Transform::apply]).
+	pub fn apply(&self, point: TypedVector<Source>) -> TypedVector<Target> {
+		self.transform.apply(point.retagged()).retagged()
+	}
+	/// Transforms a direction (see [Transform::apply_to_direction]).
+	pub fn apply_to_direction(&self, direction: TypedVector<Source>) -> TypedVector<Target> {
+		self.transform.apply_to_direction(direction.retagged()).retagged()
+	}
+	/// Transforms the bounds (see [Transform::apply_to_bounds]).
+	pub fn apply_to_bounds(&self, bounds: &TypedBounds<Source>) -> TypedBounds<Target> {
+		self.transform.apply_to_bounds(&bounds.retagged()).retagged()
+	}
+
+	/// The transformation that applies this one first and then the other one.
+	pub fn then<Next: Space>(&self, other: &SpaceTransform<Target, Next>) -> SpaceTransform<Source, Next> {
+		SpaceTransform::new(self.transform.then(&other.transform))
+	}
+	/// The transformation back from `Target` to `Source` (see [Transform::inverse]).
+	pub fn inverse(&self) -> Option<SpaceTransform<Target, Source>> {
+		self.transform.inverse().map(SpaceTransform::new)
+	}
+}
+
+impl<Source: Space, Target: Space> Default for SpaceTransform<Source, Target> {
+	fn default() -> Self {
+		Self::identity()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::common::{Bounds, ScreenVector, Vector};
+
+	fn screen_to_world() -> SpaceTransform<Screen, World> {
+		// zoomed in by 2 and scrolled by (100, 50)
+		let scroll = Transform::translation(Vector::new(-100.0, -50.0));
+		SpaceTransform::new(scroll.then(&Transform::uniform_scale(0.5)))
+	}
+
+	#[test]
+	fn converts_through_transforms() {
+		let pointer = ScreenVector::new(120.0, 70.0);
+		let position: WorldVector = screen_to_world().apply(pointer);
+		assert_eq!(position, WorldVector::new(10.0, 10.0));
+		// directions aren't translated
+		let drag: WorldVector = screen_to_world().apply_to_direction(ScreenVector::new(20.0, -10.0));
+		assert_eq!(drag, WorldVector::new(10.0, -5.0));
+
+		let bounds = ScreenBounds::new(ScreenVector::new(100.0, 50.0), ScreenVector::new(40.0, 20.0));
+		let world: WorldBounds = screen_to_world().apply_to_bounds(&bounds);
+		assert_eq!(world, WorldBounds::new(WorldVector::zero(), WorldVector::new(20.0, 10.0)));
+	}
+
+	#[test]
+	fn composes_and_inverts_transforms() {
+		let world_to_local = SpaceTransform::<World, Local>::new(Transform::translation(Vector::new(-10.0, -10.0)));
+		let screen_to_local: SpaceTransform<Screen, Local> = screen_to_world().then(&world_to_local);
+		assert_eq!(screen_to_local.apply(ScreenVector::new(120.0, 70.0)), LocalVector::zero());
+
+		let world_to_screen: SpaceTransform<World, Screen> = screen_to_world().inverse().unwrap();
+		assert_eq!(world_to_screen.apply(WorldVector::new(10.0, 10.0)), ScreenVector::new(120.0, 70.0));
+		assert_eq!(SpaceTransform::<Screen, World>::new(Transform::uniform_scale(0.0)).inverse(), None);
+	}
+
+	#[test]
+	fn keeps_calculations_within_a_space() {
+		let a = WorldBounds::new(WorldVector::new(0.0, 0.0), WorldVector::new(10.0, 10.0));
+		let b = a.translated(WorldVector::new(5.0, 5.0));
+		let overlap = WorldBounds::new(WorldVector::new(5.0, 5.0), WorldVector::new(5.0, 5.0));
+		assert_eq!(a.intersection(&b), Some(overlap));
+		// untyped coordinates work the same way
+		let untyped = Bounds::new(Vector::zero(), Vector::new(10.0, 10.0));
+		assert_eq!(untyped.center(), Vector::new(5.0, 5.0));
+		assert_eq!(a.center(), WorldVector::new(5.0, 5.0));
+	}
+}
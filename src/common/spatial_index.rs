@@ -0,0 +1,282 @@
+use std::{collections::HashMap, hash::Hash};
+
+use super::{Bounds, Number, Vector};
+
+/// How many items a node holds before it is split into 4 children.
+const NODE_CAPACITY: usize = 8;
+/// How deep the tree can get (to keep lots of overlapping items from splitting forever).
+const MAX_DEPTH: usize = 12;
+
+/// An index of items by their [Bounds] for fast hit-testing (a quadtree).
+///
+/// Items are identified by a key (e.g. an id or index into your own list of items).
+/// Queries return the keys of the matching items (in no particular order).
+///
+/// The indexed region grows automatically when items are inserted outside of it.
+/// That requires rebuilding the tree, so if you know the size of your scene, create the index [with that region](SpatialIndex::with_region).
+pub struct SpatialIndex<K> {
+	items: HashMap<K, Bounds>,
+	region: Option<Bounds>,
+	root: Node<K>,
+}
+
+struct Node<K> {
+	items: Vec<(K, Bounds)>,
+	children: Option<Box<[Node<K>; 4]>>,
+}
+
+impl<K: Copy + Eq + Hash> SpatialIndex<K> {
+	pub fn new() -> Self {
+		Self { items: HashMap::new(), region: None, root: Node::new() }
+	}
+	/// Creates an index that covers the given region without having to grow.
+	pub fn with_region(region: Bounds) -> Self {
+		Self { items: HashMap::new(), region: Some(region.normalized()), root: Node::new() }
+	}
+
+	pub fn len(&self) -> usize {
+		self.items.len()
+	}
+	pub fn is_empty(&self) -> bool {
+		self.items.is_empty()
+	}
+	pub fn contains_key(&self, key: &K) -> bool {
+		self.items.contains_key(key)
+	}
+	/// The bounds the item was inserted with (normalized).
+	pub fn get(&self, key: &K) -> Option<&Bounds> {
+		self.items.get(key)
+	}
+	/// All items and their bounds.
+	pub fn iter(&self) -> impl Iterator<Item = (&K, &Bounds)> {
+		self.items.iter()
+	}
+
+	/// Adds an item.
+	///
+	/// If there already is an item with this key, it is replaced and its old bounds are returned.
+	pub fn insert(&mut self, key: K, bounds: Bounds) -> Option<Bounds> {
+		let old = self.remove(&key);
+		let bounds = bounds.normalized();
+
+		let region = match &self.region {
+			Some(region) if region.contains_bounds(&bounds) => region.clone(),
+			Some(region) => {
+				self.grow_to_contain(Self::grown_region(region, &bounds));
+				self.region.clone().unwrap_or(bounds.clone())
+			},
+			None => {
+				let region = Self::initial_region(&bounds);
+				self.region = Some(region.clone());
+				region
+			},
+		};
+
+		self.root.insert(&region, 0, key, bounds.clone());
+		self.items.insert(key, bounds);
+		old
+	}
+	/// Moves an item to new bounds (inserts it if it doesn't exist yet).
+	///
+	/// Returns its old bounds.
+	pub fn update(&mut self, key: K, bounds: Bounds) -> Option<Bounds> {
+		self.insert(key, bounds)
+	}
+	/// Removes an item.
+	///
+	/// Returns its bounds if it existed.
+	pub fn remove(&mut self, key: &K) -> Option<Bounds> {
+		let bounds = self.items.remove(key)?;
+		if let Some(region) = &self.region {
+			self.root.remove(region, key, &bounds);
+		}
+		Some(bounds)
+	}
+	pub fn clear(&mut self) {
+		self.items.clear();
+		self.root = Node::new();
+	}
+
+	/// The items whose bounds contain the point.
+	pub fn query_point(&self, point: Vector) -> Vec<K> {
+		let mut result = Vec::new();
+		if let Some(region) = &self.region {
+			self.root.query(region, &Bounds::from(point), &mut result);
+		}
+		result
+	}
+	/// The items whose bounds intersect the area (touching counts).
+	pub fn query_bounds(&self, area: &Bounds) -> Vec<K> {
+		let mut result = Vec::new();
+		if let Some(region) = &self.region {
+			self.root.query(region, &area.normalized(), &mut result);
+		}
+		result
+	}
+	/// The item whose bounds are closest to the point (`0` if the point is inside).
+	pub fn nearest(&self, point: Vector) -> Option<K> {
+		self.nearest_within(point, Number::INFINITY)
+	}
+	/// The item whose bounds are closest to the point, if it is at most `max_distance` away.
+	pub fn nearest_within(&self, point: Vector, max_distance: Number) -> Option<K> {
+		let region = self.region.as_ref()?;
+		let mut best = None;
+		let mut best_distance = max_distance;
+		self.root.nearest(region, point, &mut best, &mut best_distance);
+		best
+	}
+
+	fn initial_region(bounds: &Bounds) -> Bounds {
+		// leave some room around the first item so the next ones don't immediately trigger a rebuild
+		let size = Number::max(bounds.width().max(bounds.height()), 1.0);
+		bounds.expand(Vector::new_square(size))
+	}
+	fn grown_region(region: &Bounds, bounds: &Bounds) -> Bounds {
+		// at least double the region so growing happens rarely
+		let merged = region.clone().combined_with(bounds);
+		merged.expand(Vector::new(region.width(), region.height()) / 2.0)
+	}
+	fn grow_to_contain(&mut self, region: Bounds) {
+		self.root = Node::new();
+		for (key, bounds) in &self.items {
+			self.root.insert(&region, 0, *key, bounds.clone());
+		}
+		self.region = Some(region);
+	}
+}
+
+impl<K: Copy + Eq + Hash> Default for SpatialIndex<K> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<K: Copy + Eq> Node<K> {
+	fn new() -> Self {
+		Self { items: Vec::new(), children: None }
+	}
+
+	/// The region of the child with the given index (0: top left, 1: top right, 2: bottom left, 3: bottom right).
+	fn quadrant(region: &Bounds, index: usize) -> Bounds {
+		let half = region.size() / 2.0;
+		let offset = Vector::new((index % 2) as Number * half.x, (index / 2) as Number * half.y);
+		Bounds::new(region.start() + offset, half)
+	}
+	/// The index of the child that completely contains the bounds, if there is one.
+	fn child_index(region: &Bounds, bounds: &Bounds) -> Option<usize> {
+		(0..4).find(|i| Self::quadrant(region, *i).contains_bounds(bounds))
+	}
+
+	fn insert(&mut self, region: &Bounds, depth: usize, key: K, bounds: Bounds) {
+		if let Some(children) = &mut self.children {
+			if let Some(i) = Self::child_index(region, &bounds) {
+				return children[i].insert(&Self::quadrant(region, i), depth + 1, key, bounds);
+			}
+		}
+		self.items.push((key, bounds));
+		if self.children.is_none() && self.items.len() > NODE_CAPACITY && depth < MAX_DEPTH {
+			self.split(region, depth);
+		}
+	}
+	fn split(&mut self, region: &Bounds, depth: usize) {
+		let mut children = Box::new([Node::new(), Node::new(), Node::new(), Node::new()]);
+		let mut remaining = Vec::new();
+		for (key, bounds) in self.items.drain(..) {
+			match Self::child_index(region, &bounds) {
+				Some(i) => children[i].insert(&Self::quadrant(region, i), depth + 1, key, bounds),
+				None => remaining.push((key, bounds)),
+			}
+		}
+		self.items = remaining;
+		self.children = Some(children);
+	}
+	fn remove(&mut self, region: &Bounds, key: &K, bounds: &Bounds) -> bool {
+		if let Some(position) = self.items.iter().position(|(k, _)| k == key) {
+			self.items.swap_remove(position);
+			return true;
+		}
+		let Some(children) = &mut self.children else { return false };
+		let Some(i) = Self::child_index(region, bounds) else { return false };
+		let removed = children[i].remove(&Self::quadrant(region, i), key, bounds);
+		// collapse children that are no longer needed
+		if children.iter().all(|child| child.children.is_none() && child.items.is_empty()) {
+			self.children = None;
+		}
+		removed
+	}
+
+	fn query(&self, region: &Bounds, area: &Bounds, result: &mut Vec<K>) {
+		result.extend(self.items.iter().filter(|(_, bounds)| bounds.intersects(area)).map(|(key, _)| *key));
+		let Some(children) = &self.children else { return };
+		for (i, child) in children.iter().enumerate() {
+			let quadrant = Self::quadrant(region, i);
+			if quadrant.intersects(area) {
+				child.query(&quadrant, area, result);
+			}
+		}
+	}
+	fn nearest(&self, region: &Bounds, point: Vector, best: &mut Option<K>, best_distance: &mut Number) {
+		for (key, bounds) in &self.items {
+			let distance = distance_to(bounds, point);
+			if distance <= *best_distance {
+				*best = Some(*key);
+				*best_distance = distance;
+			}
+		}
+		let Some(children) = &self.children else { return };
+		let mut quadrants = (0..4)
+			.map(|i| {
+				let quadrant = Self::quadrant(region, i);
+				(distance_to(&quadrant, point), i, quadrant)
+			})
+			.collect::<Vec<_>>();
+		quadrants.sort_by(|a, b| a.0.total_cmp(&b.0));
+		for (distance, i, quadrant) in quadrants {
+			if distance <= *best_distance {
+				children[i].nearest(&quadrant, point, best, best_distance);
+			}
+		}
+	}
+}
+
+fn distance_to(bounds: &Bounds, point: Vector) -> Number {
+	(bounds.clamp_point(point) - point).length()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn square(x: Number, y: Number, size: Number) -> Bounds {
+		Bounds::new(Vector::new(x, y), Vector::new_square(size))
+	}
+
+	#[test]
+	fn finds_items() {
+		let mut index = SpatialIndex::new();
+		for i in 0..100 {
+			let (x, y) = ((i % 10) as Number * 10.0, (i / 10) as Number * 10.0);
+			index.insert(i, square(x, y, 5.0));
+		}
+		index.insert(100, square(-500.0, -500.0, 1000.0));
+
+		let mut hits = index.query_point(Vector::new(12.0, 23.0));
+		hits.sort();
+		assert_eq!(hits, vec![21, 100]);
+		assert_eq!(index.query_point(Vector::new(17.0, 23.0)), vec![100]);
+
+		let mut hits = index.query_bounds(&square(12.0, 12.0, 10.0));
+		hits.sort();
+		assert_eq!(hits, vec![11, 12, 21, 22, 100]);
+
+		index.remove(&100);
+		assert!(index.query_point(Vector::new(17.0, 23.0)).is_empty());
+		assert_eq!(index.nearest(Vector::new(17.0, 23.0)), Some(21));
+		assert_eq!(index.nearest_within(Vector::new(200.0, 200.0), 10.0), None);
+
+		index.update(21, square(500.0, 500.0, 5.0));
+		assert_eq!(index.nearest(Vector::new(17.0, 23.0)), Some(22));
+		assert_eq!(index.query_point(Vector::new(502.0, 502.0)), vec![21]);
+		assert_eq!(index.len(), 100);
+	}
+}
@@ -0,0 +1,291 @@
+use std::ops;
+
+use ncollide2d::na::{Affine2, Matrix3};
+
+use super::{Bounds, Number, Vector};
+
+/// A 2D affine transformation (translation, scale, rotation, skew and combinations of those).
+///
+/// This is a domain-specific type (see [Vector]).
+/// It's stored like the transformation of a canvas (`setTransform(a, b, c, d, e, f)`) or a css `matrix(a, b, c, d, e, f)`:
+/// ```text
+/// | a c e |
+/// | b d f |
+/// | 0 0 1 |
+/// ```
+/// So a point (x, y) is transformed to (a * x + c * y + e, b * x + d * y + f).
+///
+/// # Composition
+/// `first.then(&second)` applies `first` and then `second`.
+/// `second * first` does the same (like matrix multiplication: the right side is applied first).
+///
+/// Serialized (with the `serde` feature) as `{ "a": .., "b": .., "c": .., "d": .., "e": .., "f": .. }`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Transform {
+	pub a: Number,
+	pub b: Number,
+	pub c: Number,
+	pub d: Number,
+	pub e: Number,
+	pub f: Number,
+}
+
+impl Transform {
+	pub fn new(a: Number, b: Number, c: Number, d: Number, e: Number, f: Number) -> Self {
+		Self { a, b, c, d, e, f }
+	}
+
+	/// The transformation that doesn't change anything.
+	pub fn identity() -> Self {
+		Self::new(1.0, 0.0, 0.0, 1.0, 0.0, 0.0)
+	}
+	pub fn translation(by: Vector) -> Self {
+		Self::new(1.0, 0.0, 0.0, 1.0, by.x, by.y)
+	}
+	/// Scales by x and y separately (around the origin).
+	pub fn scale(by: Vector) -> Self {
+		Self::new(by.x, 0.0, 0.0, by.y, 0.0, 0.0)
+	}
+	/// Scales by the same factor in both directions (around the origin).
+	pub fn uniform_scale(by: Number) -> Self {
+		Self::scale(Vector::new_square(by))
+	}
+	/// Scales by x and y separately around the given center.
+	pub fn scale_around(center: Vector, by: Vector) -> Self {
+		Self::translation(-center).then(&Self::scale(by)).then(&Self::translation(center))
+	}
+	/// Rotates by the angle (in radians, around the origin).
+	///
+	/// Positive angles rotate from the x-axis towards the y-axis (that's clockwise on screen, where y grows downwards).
+	pub fn rotation(angle: Number) -> Self {
+		let (sin, cos) = angle.sin_cos();
+		Self::new(cos, sin, -sin, cos, 0.0, 0.0)
+	}
+	/// Rotates by the angle (in radians) around the given center.
+	pub fn rotation_around(center: Vector, angle: Number) -> Self {
+		Self::translation(-center).then(&Self::rotation(angle)).then(&Self::translation(center))
+	}
+	/// Skews by the angles (in radians) along the x- and y-axis (like css `skew(x, y)`).
+	pub fn skew(x_angle: Number, y_angle: Number) -> Self {
+		Self::new(1.0, y_angle.tan(), x_angle.tan(), 1.0, 0.0, 0.0)
+	}
+
+	/// The transformation that applies this one first and then the other one.
+	pub fn then(&self, other: &Self) -> Self {
+		Self::new(
+			other.a * self.a + other.c * self.b,
+			other.b * self.a + other.d * self.b,
+			other.a * self.c + other.c * self.d,
+			other.b * self.c + other.d * self.d,
+			other.a * self.e + other.c * self.f + other.e,
+			other.b * self.e + other.d * self.f + other.f,
+		)
+	}
+
+	pub fn determinant(&self) -> Number {
+		self.a * self.d - self.b * self.c
+	}
+	/// The transformation that undoes this one.
+	///
+	/// Returns `None` if this transformation can't be undone (e.g. because it scales by 0).
+	pub fn inverse(&self) -> Option<Self> {
+		let determinant = self.determinant();
+		if determinant == 0.0 || !determinant.is_finite() {
+			return None;
+		}
+		Some(Self::new(
+			self.d / determinant,
+			-self.b / determinant,
+			-self.c / determinant,
+			self.a / determinant,
+			(self.c * self.f - self.d * self.e) / determinant,
+			(self.b * self.e - self.a * self.f) / determinant,
+		))
+	}
+
+	/// Transforms a point (/position).
+	pub fn apply(&self, point: Vector) -> Vector {
+		Vector::new(self.a * point.x + self.c * point.y + self.e, self.b * point.x + self.d * point.y + self.f)
+	}
+	/// Transforms a direction (/offset/size); that is without the translation.
+	pub fn apply_to_direction(&self, direction: Vector) -> Vector {
+		Vector::new(self.a * direction.x + self.c * direction.y, self.b * direction.x + self.d * direction.y)
+	}
+	/// Transforms the bounds.
+	///
+	/// # Returns
+	/// The (normalized) bounds of the transformed corners.
+	/// If the transformation rotates or skews, that is larger than the transformed area itself.
+	pub fn apply_to_bounds(&self, bounds: &Bounds) -> Bounds {
+		let [first, rest @ ..] = bounds.corners().map(|corner| self.apply(corner));
+		let (min, max) = rest.iter().fold((first, first), |(min, max), corner| (min.min(corner), max.max(corner)));
+		Bounds::new_with_end(min, max)
+	}
+
+	/// Splits this transformation into its parts.
+	///
+	/// See [Decomposition] for how to put it back together.
+	pub fn decompose(&self) -> Decomposition {
+		let scale_x = Number::sqrt(self.a.powi(2) + self.b.powi(2));
+		let rotation = self.b.atan2(self.a);
+		let scale_y = match scale_x != 0.0 {
+			true => self.determinant() / scale_x,
+			false => Number::sqrt(self.c.powi(2) + self.d.powi(2)),
+		};
+		let skew = match scale_x != 0.0 && scale_y != 0.0 {
+			true => ((self.a * self.c + self.b * self.d) / (scale_x * scale_y)).atan(),
+			false => 0.0,
+		};
+		Decomposition {
+			translation: Vector::new(self.e, self.f),
+			rotation,
+			skew,
+			scale: Vector::new(scale_x, scale_y),
+		}
+	}
+}
+
+/// The parts of a [Transform].
+///
+/// The transformation is the same as: first scale, then skew along the x-axis, then rotate and finally translate.
+/// (see [Decomposition::compose])
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Decomposition {
+	pub translation: Vector,
+	/// in radians
+	pub rotation: Number,
+	/// the skew angle along the x-axis in radians
+	pub skew: Number,
+	/// might be negative if the transformation mirrors
+	pub scale: Vector,
+}
+
+impl Decomposition {
+	pub fn compose(&self) -> Transform {
+		Transform::scale(self.scale)
+			.then(&Transform::skew(self.skew, 0.0))
+			.then(&Transform::rotation(self.rotation))
+			.then(&Transform::translation(self.translation))
+	}
+}
+
+impl Default for Transform {
+	fn default() -> Self {
+		Self::identity()
+	}
+}
+
+impl ops::Mul for Transform {
+	type Output = Self;
+
+	/// Like matrix multiplication: `(a * b)` applies `b` first and then `a`.
+	fn mul(self, rhs: Self) -> Self::Output {
+		rhs.then(&self)
+	}
+}
+
+impl ops::Mul<Vector> for Transform {
+	type Output = Vector;
+
+	/// Transforms the point (see [Transform::apply]).
+	fn mul(self, rhs: Vector) -> Self::Output {
+		self.apply(rhs)
+	}
+}
+
+impl From<Decomposition> for Transform {
+	fn from(value: Decomposition) -> Self {
+		value.compose()
+	}
+}
+
+impl From<Transform> for Decomposition {
+	fn from(value: Transform) -> Self {
+		value.decompose()
+	}
+}
+
+impl From<Affine2<Number>> for Transform {
+	fn from(value: Affine2<Number>) -> Self {
+		let m = value.matrix();
+		Self::new(m[(0, 0)], m[(1, 0)], m[(0, 1)], m[(1, 1)], m[(0, 2)], m[(1, 2)])
+	}
+}
+
+impl From<Transform> for Affine2<Number> {
+	fn from(value: Transform) -> Self {
+		let Transform { a, b, c, d, e, f } = value;
+		Affine2::from_matrix_unchecked(Matrix3::new(a, c, e, b, d, f, 0.0, 0.0, 1.0))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::f64::consts::FRAC_PI_6;
+
+	use super::*;
+
+	fn assert_close(actual: Transform, expected: Transform) {
+		let (x, y) = (actual, expected);
+		let differences = [x.a - y.a, x.b - y.b, x.c - y.c, x.d - y.d, x.e - y.e, x.f - y.f];
+		assert!(differences.iter().all(|difference| difference.abs() < 1e-9), "{actual:?} != {expected:?}");
+	}
+	fn samples() -> [Transform; 4] {
+		[
+			Transform::translation(Vector::new(3.0, -4.0)),
+			Transform::scale_around(Vector::new(1.0, 2.0), Vector::new(2.0, 0.5)),
+			Transform::rotation_around(Vector::new(5.0, 5.0), FRAC_PI_6),
+			Transform::new(1.5, 0.3, -0.7, 2.0, 10.0, -20.0),
+		]
+	}
+
+	#[test]
+	fn inverse_undoes() {
+		for transform in samples() {
+			let inverse = transform.inverse().unwrap();
+			assert_close(transform.then(&inverse), Transform::identity());
+			assert_close(inverse.then(&transform), Transform::identity());
+		}
+		assert_eq!(Transform::scale(Vector::new(0.0, 1.0)).inverse(), None);
+	}
+
+	#[test]
+	fn decomposes() {
+		let mirrored =
+			Transform::scale(Vector::new(-1.0, 2.0)).then(&Transform::translation(Vector::new(1.0, 1.0)));
+		for transform in samples().into_iter().chain([mirrored]) {
+			assert_close(transform.decompose().compose(), transform);
+		}
+		let transform = Transform::rotation(FRAC_PI_6).then(&Transform::translation(Vector::new(1.0, 2.0)));
+		let decomposition = transform.decompose();
+		assert!((decomposition.rotation - FRAC_PI_6).abs() < 1e-9);
+		assert!((decomposition.scale - Vector::new(1.0, 1.0)).length() < 1e-9);
+		assert_eq!(decomposition.translation, Vector::new(1.0, 2.0));
+	}
+
+	#[test]
+	fn applies_to_bounds() {
+		let bounds = Bounds::new(Vector::new(0.0, 0.0), Vector::new(4.0, 2.0));
+		let scaled = Transform::scale(Vector::new(-1.0, 2.0)).apply_to_bounds(&bounds);
+		assert_eq!(scaled, Bounds::new(Vector::new(-4.0, 0.0), Vector::new(4.0, 4.0)));
+
+		// the bounds of the rotated corners
+		let rotated = Transform::rotation(std::f64::consts::FRAC_PI_2).apply_to_bounds(&bounds);
+		assert!((rotated.start() - Vector::new(-2.0, 0.0)).length() < 1e-9);
+		assert!((rotated.size() - Vector::new(2.0, 4.0)).length() < 1e-9);
+	}
+
+	#[test]
+	fn converts_to_and_from_affine() {
+		for transform in samples() {
+			let affine: Affine2<Number> = transform.into();
+			assert_eq!(Transform::from(affine), transform);
+
+			let point = Vector::new(7.0, -3.0);
+			let transformed = affine * ncollide2d::na::Point2::new(point.x, point.y);
+			assert!((Vector::new(transformed.x, transformed.y) - transform.apply(point)).length() < 1e-9);
+		}
+	}
+}
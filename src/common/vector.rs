@@ -2,14 +2,26 @@ use std::ops::{self, Neg};
 
 use ncollide2d::na::{Point2, Vector2};
 
-use super::Number;
+use super::{Number, Space, Unspecified};
 
 /// A 2D-vector (/point/position/direction/..).
 ///
 /// This is a domain-specific type.
 /// Its' implementation is a reflection of the concrete usage of coordinates in this application, not an attempt to reimplement an algbraic primitive.
 ///
-/// That is also why it's not generic.
+/// That is also why it's not generic over the number type.
+/// It is generic over the coordinate [Space] though: [Vector] is the vector of an unspecified space that is used for most calculations,
+/// vectors of different spaces (e.g. [ScreenVector](super::ScreenVector) and [WorldVector](super::WorldVector)) can't be mixed
+/// and are only converted by a [SpaceTransform](super::SpaceTransform).
+///
+/// The space is a (zero-sized) public field, so vectors can still be written as literals and destructured:
+/// ```
+/// use webbit::common::{Unspecified, Vector};
+///
+/// let vector = Vector { x: 1.0, y: 2.0, space: Unspecified };
+/// let Vector { x, y, .. } = vector;
+/// assert_eq!((x, y), (1.0, 2.0));
+/// ```
 ///
 /// Goals:
 /// - keep data in our own type, not a library
@@ -20,15 +32,20 @@ use super::Number;
 /// Non-Goals:
 /// - correct mathematical interpretation (diffentiating between vector/point/direction/angle/..)
 /// - universal applicability
-#[derive(Copy, Clone, PartialEq)]
-pub struct Vector {
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct TypedVector<S: Space> {
 	pub x: Number,
 	pub y: Number,
+	/// the marker of the coordinate space (e.g. [Unspecified])
+	pub space: S,
 }
 
-impl Vector {
+/// A [TypedVector] that doesn't know which coordinate space it is in.
+pub type Vector = TypedVector<Unspecified>;
+
+impl<S: Space> TypedVector<S> {
 	pub fn new(x: Number, y: Number) -> Self {
-		Self { x, y }
+		Self { x, y, space: S::default() }
 	}
 
 	/// Creates a new Vector where x and y have the same value.
@@ -68,8 +85,8 @@ impl Vector {
 	pub fn norm_infinity(&self) -> Number {
 		Number::max(self.x.abs(), self.y.abs())
 	}
-	pub fn abs(&self) -> Vector {
-		Self { x: self.x.abs(), y: self.y.abs() }
+	pub fn abs(&self) -> Self {
+		Self::new(self.x.abs(), self.y.abs())
 	}
 
 	/// The normal vector to this vector.
@@ -96,7 +113,7 @@ impl Vector {
 	}
 
 	/// The dot-product with the other vector.
-	pub fn dot(&self, other: &Vector) -> Number {
+	pub fn dot(&self, other: &Self) -> Number {
 		self.x * other.x + self.y * other.y
 	}
 	/// The cross-product with the other vector.
@@ -106,19 +123,19 @@ impl Vector {
 	///
 	/// - This happens to be the same as the dot product between other and the normal vector of self.
 	/// - a cross b = - (b cross a)
-	pub fn cross(&self, other: &Vector) -> Number {
+	pub fn cross(&self, other: &Self) -> Number {
 		self.normal().dot(other)
 	}
 	/// The projection of self onto other:
 	/// - It's the purely parallel (to other) component of self.
 	/// - Equal to the vector self minus its rejection on other.
-	pub fn projection_on(&self, other: &Vector) -> Vector {
+	pub fn projection_on(&self, other: &Self) -> Self {
 		self.dot(&other.unit()) * other.unit()
 	}
 	/// The rejection of self from other:
 	/// - It's the purely orthogonal (to other) component of self.
 	/// - Equal to the vector self minus its projection on other.
-	pub fn rejection_on(&self, other: &Vector) -> Vector {
+	pub fn rejection_on(&self, other: &Self) -> Self {
 		*self - self.projection_on(other)
 	}
 
@@ -139,17 +156,17 @@ impl Vector {
 		self.dot(other) < 0.0
 	}
 
-	pub fn min(mut self, other: &Vector) -> Vector {
+	pub fn min(mut self, other: &Self) -> Self {
 		self.set_to_min_with(other);
 		self
 	}
-	pub fn max(mut self, other: &Vector) -> Vector {
+	pub fn max(mut self, other: &Self) -> Self {
 		self.set_to_max_with(other);
 		self
 	}
 
 	/// Sets each coordinate of this vector to the minimum of itself and the corresponding coordinate of the other vector.
-	pub fn set_to_min_with(&mut self, other: &Vector) {
+	pub fn set_to_min_with(&mut self, other: &Self) {
 		if other.x < self.x {
 			self.x = other.x
 		};
@@ -158,7 +175,7 @@ impl Vector {
 		};
 	}
 	/// Sets each coordinate of this vector to the maximum of itself and the corresponding coordinate of the other vector.
-	pub fn set_to_max_with(&mut self, other: &Vector) {
+	pub fn set_to_max_with(&mut self, other: &Self) {
 		if other.x > self.x {
 			self.x = other.x
 		};
@@ -166,51 +183,58 @@ impl Vector {
 			self.y = other.y
 		};
 	}
+
+	/// The same coordinates in another space.
+	///
+	/// Only for the boundaries to untyped code; convert between spaces with a [SpaceTransform](super::SpaceTransform).
+	pub(crate) fn retagged<T: Space>(self) -> TypedVector<T> {
+		TypedVector::new(self.x, self.y)
+	}
 }
 
 // TODO: implement all these operations for Borrows instead of owned values -> doesn't matter for performance; better ergonomics (don't have to clone() everywhere)
 
-impl ops::Add for Vector {
+impl<S: Space> ops::Add for TypedVector<S> {
 	type Output = Self;
 
-	fn add(self, rhs: Vector) -> Self::Output {
-		Self { x: self.x + rhs.x, y: self.y + rhs.y }
+	fn add(self, rhs: Self) -> Self::Output {
+		Self::new(self.x + rhs.x, self.y + rhs.y)
 	}
 }
 
-impl Neg for Vector {
+impl<S: Space> Neg for TypedVector<S> {
 	type Output = Self;
 
 	fn neg(self) -> Self::Output {
-		Self::Output { x: -self.x, y: -self.y }
+		Self::new(-self.x, -self.y)
 	}
 }
 
-impl ops::Sub for Vector {
+impl<S: Space> ops::Sub for TypedVector<S> {
 	type Output = Self;
 
-	fn sub(self, rhs: Vector) -> Self::Output {
-		Self { x: self.x - rhs.x, y: self.y - rhs.y }
+	fn sub(self, rhs: Self) -> Self::Output {
+		Self::new(self.x - rhs.x, self.y - rhs.y)
 	}
 }
 
-impl ops::Mul<Vector> for Number {
-	type Output = Vector;
+impl<S: Space> ops::Mul<TypedVector<S>> for Number {
+	type Output = TypedVector<S>;
 
-	fn mul(self, rhs: Vector) -> Self::Output {
-		Vector { x: self * rhs.x, y: self * rhs.y }
+	fn mul(self, rhs: TypedVector<S>) -> Self::Output {
+		TypedVector::new(self * rhs.x, self * rhs.y)
 	}
 }
 
-impl ops::Mul<Number> for Vector {
+impl<S: Space> ops::Mul<Number> for TypedVector<S> {
 	type Output = Self;
 
 	fn mul(self, rhs: Number) -> Self::Output {
-		Self { x: self.x * rhs, y: self.y * rhs }
+		Self::new(self.x * rhs, self.y * rhs)
 	}
 }
 
-impl ops::Mul<Self> for Vector {
+impl<S: Space> ops::Mul<Self> for TypedVector<S> {
 	type Output = Number;
 
 	fn mul(self, rhs: Self) -> Self::Output {
@@ -218,15 +242,15 @@ impl ops::Mul<Self> for Vector {
 	}
 }
 
-impl ops::Div<Number> for Vector {
+impl<S: Space> ops::Div<Number> for TypedVector<S> {
 	type Output = Self;
 
 	fn div(self, rhs: Number) -> Self::Output {
-		Self { x: self.x / rhs, y: self.y / rhs }
+		Self::new(self.x / rhs, self.y / rhs)
 	}
 }
 
-impl ops::Index<usize> for Vector {
+impl<S: Space> ops::Index<usize> for TypedVector<S> {
 	type Output = Number;
 
 	fn index(&self, index: usize) -> &Self::Output {
@@ -238,7 +262,7 @@ impl ops::Index<usize> for Vector {
 	}
 }
 
-impl ops::IndexMut<usize> for Vector {
+impl<S: Space> ops::IndexMut<usize> for TypedVector<S> {
 	fn index_mut(&mut self, index: usize) -> &mut Self::Output {
 		match index {
 			0 => &mut self.x,
@@ -248,6 +272,22 @@ impl ops::IndexMut<usize> for Vector {
 	}
 }
 
+/// Serialized as `[x, y]`.
+#[cfg(feature = "serde")]
+impl<S: Space> serde::Serialize for TypedVector<S> {
+	fn serialize<T: serde::Serializer>(&self, serializer: T) -> Result<T::Ok, T::Error> {
+		[self.x, self.y].serialize(serializer)
+	}
+}
+
+/// Deserialized from `[x, y]`.
+#[cfg(feature = "serde")]
+impl<'de, S: Space> serde::Deserialize<'de> for TypedVector<S> {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		<[Number; 2]>::deserialize(deserializer).map(|[x, y]| Self::new(x, y))
+	}
+}
+
 impl From<[f64; 2]> for Vector {
 	fn from(value: [f64; 2]) -> Self {
 		Self::new(value[0], value[1])
@@ -283,3 +323,16 @@ impl From<Vector2<Number>> for Vector {
 		Self::new(value[0], value[1])
 	}
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn serializes_as_array() {
+		let vector = Vector::new(1.5, -2.0);
+		assert_eq!(serde_json::to_string(&vector).unwrap(), "[1.5,-2.0]");
+		assert_eq!(serde_json::from_str::<Vector>("[1.5, -2]").unwrap(), vector);
+		assert!(serde_json::from_str::<Vector>("[1.5]").is_err());
+	}
+}
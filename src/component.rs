@@ -1,9 +1,16 @@
-use std::{ops::Deref, rc::Rc};
+use std::{
+	cell::{Cell, RefCell},
+	ops::Deref,
+	rc::Rc,
+};
 
-use anyhow::Result;
-use web_sys::Element;
+use anyhow::{anyhow, Result};
 
-use super::ComponentContent;
+use super::{ComponentContent, DynamicComponent};
+use crate::{
+	dom::{Dom, WebDom},
+	errors::{ComponentError, ConsoleErrorHandler, ErrorHandler, LifecycleStage},
+};
 
 /// A ui component.
 /// Meaning a struct that represents a certain piece of ui (and removes that when it is dropped).
@@ -40,8 +47,26 @@ use super::ComponentContent;
 /// - implement unmount() such that it:
 ///		- unmounts your ui elements
 ///		- deregisters your `EventListener`s
-pub struct Component<C: ComponentContent> {
+///
+/// # Children
+/// Child components can be stored as fields (as above) or registered with the component (opt-in):
+/// - [append_child](Component::append_child) mounts a child in this component and registers it
+/// - [update](Component::update) updates this component and then its children (depth-first)
+/// - when the component is dropped, its children are destroyed first (in the order they were registered)
+/// - [tree](Component::tree) shows the registered hierarchy (for debugging)
+///
+/// # Errors
+/// Failures while the component is dropped (and the ones of the `_or_report` methods) are reported as [ComponentError].
+/// They go to the console unless you give the component an [ErrorHandler] (see [with_error_handler](Component::with_error_handler)).
+///
+/// # Dom
+/// Components live in the browser's DOM ([WebDom::shared]) unless they are made in another [Dom] (see [make_in](Component::make_in)).
+pub struct Component<C: ComponentContent<D>, D: Dom = WebDom> {
+	dom: Rc<D>,
 	content: Rc<C>,
+	children: RefCell<Vec<Box<dyn DynamicComponent<D>>>>,
+	mounted: Cell<bool>,
+	error_handler: RefCell<Report>,
 }
 impl<C: ComponentContent> Component<C> {
 	/// If you can (if you don't need to reference the content from the outside) use `Component::make` instead.
@@ -50,39 +75,189 @@ impl<C: ComponentContent> Component<C> {
 		Self::make(content)
 	}
 	pub fn make(content: C) -> Self {
-		Self::make_sharable(Rc::new(content))
+		Self::make_in(WebDom::shared(), content)
 	}
 	pub fn make_sharable(content: Rc<C>) -> Self {
-		Self { content }
+		Self::make_sharable_in(WebDom::shared(), content)
+	}
+}
+impl<C: ComponentContent<D>, D: Dom> Component<C, D> {
+	/// Like [make](Component::make), but in the dom.
+	pub fn make_in(dom: Rc<D>, content: C) -> Self {
+		Self::make_sharable_in(dom, Rc::new(content))
+	}
+	/// Like [make_sharable](Component::make_sharable), but in the dom.
+	pub fn make_sharable_in(dom: Rc<D>, content: Rc<C>) -> Self {
+		let handler = ConsoleErrorHandler::new(name::<C>().to_owned());
+		Self {
+			dom,
+			content,
+			children: RefCell::new(Vec::new()),
+			mounted: Cell::new(false),
+			error_handler: RefCell::new(Rc::new(move |error| handler.handle(error))),
+		}
+	}
+	/// Reports the failures of this component to the handler (instead of the console).
+	///
+	/// The handler should already have the environment of the component, e.g. `handler.clone_for("Toolbar")`.
+	pub fn with_error_handler(self, handler: impl ErrorHandler<ComponentError> + 'static) -> Self {
+		self.set_error_handler(handler);
+		self
+	}
+	/// Like [with_error_handler](Component::with_error_handler), but for a component that is already in use.
+	pub fn set_error_handler(&self, handler: impl ErrorHandler<ComponentError> + 'static) {
+		self.set_report(Rc::new(move |error| handler.handle(error)));
+	}
+	/// Reports the failures of this component with the function (e.g. one that is shared by multiple components).
+	pub(crate) fn set_report(&self, report: Report) {
+		if let Ok(mut error_handler) = self.error_handler.try_borrow_mut() {
+			*error_handler = report;
+		}
+	}
+
+	/// The name of the content type (without its module path).
+	pub fn name(&self) -> &'static str {
+		name::<C>()
+	}
+	/// Reports the error (if any) to the error handler of this component.
+	///
+	/// # Returns
+	/// Whether there was no error.
+	pub fn report(&self, stage: LifecycleStage, result: Result<()>) -> bool {
+		let Err(error) = result else { return true };
+		// not borrowed while the handler runs (so it can replace itself)
+		let Ok(report) = self.error_handler.try_borrow().map(|report| report.clone()) else { return false };
+		report(ComponentError { component: self.name().to_owned(), stage, error });
+		false
+	}
+	/// Like [mount_in](Component::mount_in), but reports failures to the error handler.
+	pub fn mount_or_report(&self, element: &D::Node) -> bool {
+		self.report(LifecycleStage::Mount, self.mount_in(element))
+	}
+	/// Like [update](Component::update), but reports failures to the error handler.
+	pub fn update_or_report(&self) -> bool {
+		self.report(LifecycleStage::Update, self.update())
 	}
 
-	pub fn mount_in(&self, element: &Element) -> Result<()> {
-		element.append_child(self.content.element()).or(Err(anyhow::anyhow!("Failed to mount component")))?;
+	/// Mounts the component in the element (unmounting it first if it is mounted somewhere else).
+	///
+	/// Runs the mount hooks of the content (see [ComponentContent]).
+	pub fn mount_in(&self, element: &D::Node) -> Result<()> {
+		if self.mounted.get() {
+			self.unmount()?;
+		}
+		self.content.before_mount(element)?;
+		self.content.mount_in(element)?;
+		self.mounted.set(true);
+		self.content.mounted()?;
 
 		Ok(())
 	}
 
+	/// Updates this component and then its children (depth-first).
 	pub fn update(&self) -> Result<()> {
 		self.content.update()?;
 
+		let children = self.children.try_borrow().or(Err(anyhow!("Children of component are in use")))?;
+		for child in children.iter() {
+			child.update()?;
+		}
+
 		Ok(())
 	}
 
+	/// Removes this component from the DOM.
+	///
+	/// Runs the unmount hooks of the content (see [ComponentContent]) if the component is mounted.
+	/// Registered children stay mounted inside of it (so they come back when it is mounted again).
 	pub fn unmount(&self) -> Result<()> {
-		self.content.element().remove();
+		if !self.mounted.get() {
+			return Ok(());
+		}
+		self.content.before_unmount()?;
+		self.content.unmount()?;
+		self.mounted.set(false);
+		self.content.unmounted()?;
+
+		Ok(())
+	}
+	pub fn is_mounted(&self) -> bool {
+		self.mounted.get()
+	}
+
+	/// Registers the child, so it's updated and destroyed with this component.
+	///
+	/// This doesn't mount the child (use [append_child](Component::append_child) for that).
+	pub fn adopt(&self, child: impl DynamicComponent<D> + 'static) -> Result<()> {
+		let mut children = self.children.try_borrow_mut().or(Err(anyhow!("Children of component are in use")))?;
+		children.push(Box::new(child));
+
+		Ok(())
+	}
+	/// Mounts the child in this component and registers it (see [adopt](Component::adopt)).
+	pub fn append_child(&self, child: impl DynamicComponent<D> + 'static) -> Result<()> {
+		child.mount_in(self.content.element())?;
+		self.adopt(child)
+	}
+	/// Destroys all registered children (in the order they were registered).
+	pub fn clear_children(&self) -> Result<()> {
+		let mut children = self.children.try_borrow_mut().or(Err(anyhow!("Children of component are in use")))?;
+		// dropped outside of the borrow, so their destruction can't run into it
+		let removed = std::mem::take(&mut *children);
+		drop(children);
+		drop(removed);
 
 		Ok(())
 	}
+	pub fn child_count(&self) -> usize {
+		self.children.try_borrow().map(|children| children.len()).unwrap_or(0)
+	}
+
+	/// Visits this component and then its registered children (depth-first, with their depth below the start).
+	pub fn walk(&self, depth: usize, visit: &mut dyn FnMut(usize, &dyn DynamicComponent<D>)) {
+		visit(depth, self);
+		let Ok(children) = self.children.try_borrow() else { return };
+		for child in children.iter() {
+			child.walk(depth + 1, visit);
+		}
+	}
+	/// The type names of this component and its registered children (indented by depth, one per line).
+	pub fn tree(&self) -> String {
+		let mut tree = String::new();
+		self.walk(0, &mut |depth, component| {
+			tree.push_str(&"\t".repeat(depth));
+			tree.push_str(component.type_name());
+			tree.push('\n');
+		});
+		tree
+	}
 }
 
-impl<C: ComponentContent> Drop for Component<C> {
+impl<C: ComponentContent<D>, D: Dom> Drop for Component<C, D> {
 	fn drop(&mut self) {
-		self.content.destroy().unwrap();
-		self.content.element().remove();
+		// children first
+		let children = std::mem::take(self.children.get_mut());
+		drop(children);
+		self.report(LifecycleStage::Unmount, self.unmount());
+		self.report(LifecycleStage::Destroy, self.content.destroy());
+		self.dom.remove(self.content.element());
+	}
+}
+
+/// Reports the failures of a component (see [Component::set_error_handler]).
+pub(crate) type Report = Rc<dyn Fn(ComponentError)>;
+
+/// The type name of the content without its module path (e.g. `Slider` for `webbit::components::Slider<f64>`).
+fn name<C>() -> &'static str {
+	let name = std::any::type_name::<C>();
+	let end = name.find('<').unwrap_or(name.len());
+	match name[..end].rfind("::") {
+		Some(start) => &name[start + 2..],
+		None => name,
 	}
 }
 
-impl<T: ComponentContent> Deref for Component<T> {
+impl<T: ComponentContent<D>, D: Dom> Deref for Component<T, D> {
 	type Target = T;
 
 	fn deref(&self) -> &Self::Target {
@@ -1,7 +1,8 @@
 use std::rc::Rc;
 
 use anyhow::{Ok, Result};
-use web_sys::Element;
+
+use crate::dom::{Dom, DomNode, WebDom};
 
 /// Implement this to be able to make ui components from your type.
 ///
@@ -39,7 +40,22 @@ use web_sys::Element;
 ///		...
 /// }
 /// ```
-pub trait ComponentContent {
+///
+/// # Lifecycle
+/// [Component] (and [DynamicComponent]) call these in order (override the ones you need):
+/// 1. [before_mount](ComponentContent::before_mount), [mount_in](ComponentContent::mount_in), [mounted](ComponentContent::mounted)
+/// 2. [before_unmount](ComponentContent::before_unmount), [unmount](ComponentContent::unmount), [unmounted](ComponentContent::unmounted)
+///    (when the component is unmounted, mounted somewhere else or dropped while mounted)
+/// 3. [destroy](ComponentContent::destroy) (when the component is dropped)
+///
+/// # Dom
+/// Contents are made for a [Dom] (the browser's [WebDom] by default).
+/// Implement `ComponentContent<D>` for all `D: Dom` to make the component work in any document (e.g. a [MemoryDom](crate::dom::MemoryDom) in native tests).
+/// The hooks don't get the dom (nodes mount themselves, see [DomNode]); store an `Rc<D>` in the content if it needs one.
+///
+/// [Component]: crate::Component
+/// [DynamicComponent]: crate::DynamicComponent
+pub trait ComponentContent<D: Dom = WebDom> {
 	// fn new(params: I) -> (Self, Node);
 
 	// /// Mounts the ui of this component in the given element.
@@ -55,12 +71,35 @@ pub trait ComponentContent {
 
 	/// The element that represents this Component in the DOM.
 	/// This should always return the same element.
-	fn element(&self) -> &Element;
-	fn mount_in(&self, element: &Element) -> Result<()> {
-		element.append_child(self.element()).or(Err(anyhow::anyhow!("Failed to mount component")))?;
+	fn element(&self) -> &D::Node;
+	/// Called before the component is mounted in the element.
+	fn before_mount(&self, _parent: &D::Node) -> Result<()> {
+		Ok(())
+	}
+	/// Mounts the element of this component in the element.
+	fn mount_in(&self, element: &D::Node) -> Result<()> {
+		element.append(self.element()).or(Err(anyhow::anyhow!("Failed to mount component")))?;
 
 		Ok(())
 	}
+	/// Called after the component was mounted (e.g. to start observers that need the element in the DOM).
+	fn mounted(&self) -> Result<()> {
+		Ok(())
+	}
+	/// Called before the component is unmounted (e.g. to stop observers).
+	fn before_unmount(&self) -> Result<()> {
+		Ok(())
+	}
+	/// Removes the element of this component from the DOM.
+	fn unmount(&self) -> Result<()> {
+		self.element().detach();
+
+		Ok(())
+	}
+	/// Called after the component was unmounted.
+	fn unmounted(&self) -> Result<()> {
+		Ok(())
+	}
 	fn update(&self) -> Result<()> {
 		Ok(())
 	}
@@ -1,56 +1,111 @@
 use std::rc::Rc;
 
 use anyhow::Result;
-use web_sys::{HtmlDivElement, PointerEvent};
+use web_sys::PointerEvent;
 
 use crate::{
-	elements::*,
-	events::{BubbleStopper, SharedEventListener},
+	dom::{Dom, DomListeners, WebDom},
+	errors::ElementError,
+	events::CustomEventListener,
 	ComponentContent,
 };
 
-pub struct Button {
-	element: HtmlDivElement,
+/// A clickable div.
+///
+/// `on_click` is an `Rc<CustomEventListener>` (it was a `SharedEventListener`),
+/// so [set_handler](CustomEventListener::set_handler) returns a `Result` that is usually ignored with `_ =`.
+/// Its handlers get `PointerEvent`s in the browser and [DomEvent](crate::dom::DomEvent)s in a [MemoryDom](crate::dom::MemoryDom).
+pub struct Button<D: Dom = WebDom> {
+	dom: Rc<D>,
+	element: D::Node,
+	listeners: DomListeners<D>,
 
-	pub on_click: SharedEventListener<PointerEvent>,
+	pub on_click: Rc<CustomEventListener<D::PointerEvent>>,
 }
 impl Button {
 	pub fn new(name: Option<&str>, css: &str) -> Self {
-		let element = styled(div(), css);
-		BubbleStopper::new(element.clone().into(), "click");
-		BubbleStopper::new(element.clone().into(), "pointerdown");
-		BubbleStopper::new(element.clone().into(), "pointermove");
-		BubbleStopper::new(element.clone().into(), "pointerup");
-		BubbleStopper::new(element.clone().into(), "contextmenu");
-		if let Some(name) = name {
-			element.set_text_content(Some(name));
-		}
-
-		let on_click = SharedEventListener::<PointerEvent>::new(element.clone().into(), "click");
-
-		Self { element, on_click }
+		Self::new_in(WebDom::shared(), name, css).unwrap()
 	}
 
-	pub fn new_with_handler(
-		name: Option<&str>,
-		css: &str,
-		on_click: impl FnMut(PointerEvent) + 'static,
-	) -> Self {
+	pub fn new_with_handler(name: Option<&str>, css: &str, on_click: impl FnMut(PointerEvent) + 'static) -> Self {
 		let this = Self::new(name, css);
-		this.on_click.set_handler(on_click);
+		_ = this.on_click.set_handler(on_click);
 		this
 	}
+}
+impl<D: Dom> Button<D> {
+	/// Like [new](Button::new), but in the dom (and returns an error instead of panicking).
+	pub fn new_in(dom: Rc<D>, name: Option<&str>, css: &str) -> Result<Self, ElementError> {
+		dom.register_default_style("Button", super::styles::BUTTON);
+		let element = dom.create_styled("div", "button")?;
+		dom.add_class(&element, css);
+		if let Some(name) = name {
+			dom.set_text(&element, name);
+		}
+
+		let listeners = DomListeners::new(dom.clone());
+		listeners.stop_bubbling(&element, &super::CONTROL_EVENTS);
+		let on_click = Rc::new(CustomEventListener::new());
+		listeners.add(&element, "click", {
+			let on_click = on_click.clone();
+			move |event| {
+				if let Some(event) = D::pointer_event(event) {
+					_ = on_click.fire(event);
+				}
+			}
+		});
+
+		Ok(Self { dom, element, listeners, on_click })
+	}
 
 	pub fn set_text(&self, text: &str) {
-		self.element.set_text_content(Some(text));
+		self.dom.set_text(&self.element, text);
 	}
 }
-impl ComponentContent for Button {
-	fn element(&self) -> &web_sys::Element {
+impl<D: Dom> ComponentContent<D> for Button<D> {
+	fn element(&self) -> &D::Node {
 		&self.element
 	}
 	fn destroy(&self) -> Result<()> {
+		self.listeners.clear();
 		self.on_click.remove_handler();
 		Ok(())
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use std::cell::Cell;
+
+	use super::*;
+	use crate::{dom::MemoryDom, Component};
+
+	#[test]
+	fn fires_clicks_without_bubbling() {
+		let dom = MemoryDom::new();
+		let root = dom.create_element("div").unwrap();
+		let bubbled = Rc::new(Cell::new(false));
+		dom.add_listener(&root, "click", {
+			let bubbled = bubbled.clone();
+			Box::new(move |_| bubbled.set(true))
+		});
+
+		let button = Component::make_in(dom.clone(), Button::new_in(dom.clone(), Some("Save"), "primary").unwrap());
+		assert!(button.mount_or_report(&root));
+		let clicks = Rc::new(Cell::new(0));
+		_ = button.on_click.set_handler({
+			let clicks = clicks.clone();
+			move |_| clicks.set(clicks.get() + 1)
+		});
+
+		dom.click(button.element());
+		dom.click(button.element());
+		assert_eq!(clicks.get(), 2);
+		assert!(!bubbled.get());
+		assert_eq!(dom.text(&root), "Save");
+		assert_eq!(dom.find_by_class(&root, "primary"), vec![button.element().clone()]);
+
+		drop(button);
+		assert!(dom.children(&root).is_empty());
+	}
+}
@@ -1,65 +1,111 @@
 use std::rc::Rc;
 
 use anyhow::Result;
-use wasm_bindgen::JsCast;
-use web_sys::{Event, HtmlDivElement, HtmlInputElement};
 
 use crate::{
-	elements::*,
-	events::{BubbleStopper, CustomEventListener, SharedEventListener},
+	dom::{Dom, DomListeners, WebDom},
+	errors::ElementError,
+	events::CustomEventListener,
 	ComponentContent,
 };
 
-pub struct Checkbox {
-	element: HtmlDivElement,
+pub struct Checkbox<D: Dom = WebDom> {
+	dom: Rc<D>,
+	element: D::Node,
+	checkbox: D::Node,
 
 	pub on_change: CustomEventListener<bool>,
 
-	on_click: SharedEventListener<Event>,
-
-	css: &'static str,
+	listeners: DomListeners<D>,
 }
 impl Checkbox {
 	pub fn new(name: Option<&str>, value: bool, css: &'static str) -> Rc<Self> {
-		let element = styled(div(), css);
-		BubbleStopper::new(element.clone().into(), "click");
-		BubbleStopper::new(element.clone().into(), "pointerdown");
-		BubbleStopper::new(element.clone().into(), "pointermove");
-		BubbleStopper::new(element.clone().into(), "pointerup");
-		BubbleStopper::new(element.clone().into(), "contextmenu");
+		Self::new_in(WebDom::shared(), name, value, css).unwrap()
+	}
+}
+impl<D: Dom> Checkbox<D> {
+	/// Like [new](Checkbox::new), but in the dom (and returns an error instead of panicking).
+	pub fn new_in(dom: Rc<D>, name: Option<&str>, value: bool, css: &str) -> Result<Rc<Self>, ElementError> {
+		let element = dom.create_styled("div", css)?;
 
 		// checkbox
-		let checkbox = on(&element, checkbox(value));
+		let checkbox = dom.create_input("checkbox")?;
+		dom.set_checked(&checkbox, value);
+		dom.append_child(&element, &checkbox)?;
 		// label
 		if let Some(name) = name {
-			on(&element, label(name));
+			let label = dom.create_element("label")?;
+			dom.set_text(&label, name);
+			dom.append_child(&element, &label)?;
 		}
 
-		let this = Rc::new(Self {
-			element,
+		let listeners = DomListeners::new(dom.clone());
+		listeners.stop_bubbling(&element, &super::CONTROL_EVENTS);
 
-			on_change: CustomEventListener::new(),
-			on_click: SharedEventListener::<Event>::new(checkbox.clone().into(), "change"),
-			css,
-		});
+		let this = Rc::new(Self { dom, element, checkbox, on_change: CustomEventListener::new(), listeners });
 
-		this.on_click.set_handler({
+		this.listeners.add(&this.checkbox, "change", {
 			let this = this.clone();
-			move |event| {
-				let target = event.target().unwrap().dyn_into::<HtmlInputElement>().unwrap();
-				this.on_change.fire(target.checked());
+			move |_| {
+				this.on_change.fire(this.get_value());
 			}
 		});
 
-		this
+		Ok(this)
+	}
+
+	/// Set the value displayed by the ui component.
+	///
+	/// Does not trigger `on_change`!
+	pub fn set_value(&self, value: bool) {
+		self.dom.set_checked(&self.checkbox, value);
+	}
+
+	pub fn get_value(&self) -> bool {
+		self.dom.checked(&self.checkbox).unwrap_or(false)
 	}
 }
-impl ComponentContent for Checkbox {
-	fn element(&self) -> &web_sys::Element {
+impl<D: Dom> ComponentContent<D> for Checkbox<D> {
+	fn element(&self) -> &D::Node {
 		&self.element
 	}
 	fn destroy(&self) -> Result<()> {
-		self.on_click.remove_handler();
+		self.listeners.clear();
 		Ok(())
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use std::cell::RefCell;
+
+	use super::*;
+	use crate::dom::MemoryDom;
+
+	#[test]
+	fn toggles() {
+		let dom = MemoryDom::new();
+		let checkbox = Checkbox::new_in(dom.clone(), Some("Snap"), false, "setting").unwrap();
+		let changes = Rc::new(RefCell::new(Vec::new()));
+		_ = checkbox.on_change.set_handler({
+			let changes = changes.clone();
+			move |checked| changes.borrow_mut().push(checked)
+		});
+		let input = dom.children(checkbox.element())[0].clone();
+
+		dom.toggle(&input);
+		dom.toggle(&input);
+		dom.toggle(&input);
+		assert_eq!(*changes.borrow(), vec![true, false, true]);
+		assert!(checkbox.get_value());
+
+		checkbox.set_value(false);
+		assert!(!checkbox.get_value());
+		assert_eq!(changes.borrow().len(), 3);
+		assert_eq!(dom.text(checkbox.element()), "Snap");
+
+		checkbox.destroy().unwrap();
+		dom.toggle(&input);
+		assert_eq!(changes.borrow().len(), 3);
+	}
+}
@@ -5,6 +5,7 @@ use wasm_bindgen::JsCast;
 use web_sys::{HtmlDivElement, HtmlInputElement, InputEvent};
 
 use crate::{
+	common::Color,
 	elements::*,
 	events::{BubbleStopper, CustomEventListener, SharedEventListener},
 	ComponentContent,
@@ -15,13 +16,13 @@ pub struct ColorSelector {
 	color: HtmlInputElement,
 	text: HtmlInputElement,
 
-	pub on_change: CustomEventListener<String>,
+	pub on_change: CustomEventListener<Color>,
 
 	color_change: SharedEventListener<InputEvent>,
 	text_change: SharedEventListener<InputEvent>,
 }
 impl ColorSelector {
-	pub fn new(name: Option<&str>, value: &str, css: &str) -> Rc<Self> {
+	pub fn new(name: Option<&str>, value: &Color, css: &str) -> Rc<Self> {
 		let element = styled(div(), css);
 		BubbleStopper::new(element.clone().into(), "click");
 		BubbleStopper::new(element.clone().into(), "pointerdown");
@@ -34,10 +35,10 @@ impl ColorSelector {
 			on(&element, label(name));
 		}
 		// color
-		let color = on(&element, color(value));
+		let color = on(&element, color(&value.with_alpha(1.0).to_hex()));
 		let color_change = SharedEventListener::<InputEvent>::new(color.clone().into(), "input");
 		// text
-		let text = on(&element, text(value));
+		let text = on(&element, text(&value.to_hex()));
 		let text_change = SharedEventListener::<InputEvent>::new(text.clone().into(), "input");
 
 		let this =
@@ -48,15 +49,20 @@ impl ColorSelector {
 			move |event| {
 				let target = event.target().unwrap().dyn_into::<HtmlInputElement>().unwrap();
 				this.text.set_value(&target.value());
-				this.on_change.fire(target.value());
+				if let Ok(value) = target.value().parse::<Color>() {
+					this.on_change.fire(value);
+				}
 			}
 		});
 		this.text_change.set_handler({
 			let this = this.clone();
 			move |event| {
 				let target = event.target().unwrap().dyn_into::<HtmlInputElement>().unwrap();
-				this.color.set_value(&target.value());
-				this.on_change.fire(target.value());
+				// ignore text that is not (yet) a valid color
+				if let Ok(value) = target.value().parse::<Color>() {
+					this.color.set_value(&value.with_alpha(1.0).to_hex());
+					this.on_change.fire(value);
+				}
 			}
 		});
 
@@ -66,9 +72,15 @@ impl ColorSelector {
 	/// Set the value displayed by the ui component.
 	///
 	/// Does not trigger `on_change`!
-	pub fn set_value(&self, value: String) {
-		self.text.set_value(&value);
-		self.color.set_value(&value);
+	pub fn set_value(&self, value: &Color) {
+		self.text.set_value(&value.to_hex());
+		// the color input only supports opaque colors in #rrggbb notation
+		self.color.set_value(&value.with_alpha(1.0).to_hex());
+	}
+
+	/// The color currently entered, if it is valid.
+	pub fn get_value(&self) -> Option<Color> {
+		self.text.value().parse::<Color>().ok()
 	}
 }
 impl ComponentContent for ColorSelector {
@@ -1,55 +1,123 @@
 use std::rc::Rc;
 
 use anyhow::Result;
-use web_sys::{HtmlDivElement, PointerEvent};
 
-use crate::{components::Button, elements::*, events::BubbleStopper, Component, ComponentContent};
+use crate::{
+	components::Button,
+	dom::{Dom, DomListeners, WebDom},
+	errors::{ComponentErrorHandler, ElementError},
+	Component, ComponentContent,
+};
 
-pub struct ContextMenu {
-	element: HtmlDivElement,
+pub struct ContextMenu<D: Dom = WebDom> {
+	element: D::Node,
 
-	quick_actions: Vec<Component<Button>>,
-	actions: Vec<Component<Button>>,
+	/// the buttons with the names of their actions
+	quick_actions: Vec<(&'static str, Component<Button<D>, D>)>,
+	actions: Vec<(&'static str, Component<Button<D>, D>)>,
+
+	listeners: DomListeners<D>,
 }
 
-pub type MenuAction = (&'static str, Box<dyn FnMut(PointerEvent)>);
+/// The name of an action and what it does when its button is clicked.
+pub type MenuAction<D = WebDom> = (&'static str, Box<dyn FnMut(<D as Dom>::PointerEvent)>);
 
 impl ContextMenu {
+	/// Failures of the buttons go to the console (see [with_error_handler](ContextMenu::with_error_handler)).
 	pub fn new(quick_actions: Vec<MenuAction>, actions: Vec<MenuAction>, css: &'static str) -> Self {
-		let element = styled(div(), ["context-menu", css].join(" ").as_str());
-		BubbleStopper::new(element.clone().into(), "click");
-		BubbleStopper::new(element.clone().into(), "pointerdown");
-		BubbleStopper::new(element.clone().into(), "pointermove");
-		BubbleStopper::new(element.clone().into(), "pointerup");
-		BubbleStopper::new(element.clone().into(), "contextmenu");
-
-		let quick_action_div = on(&element, styled(div(), "context-menu-quick-section"));
-		let action_div = on(&element, styled(div(), "context-menu-section"));
-
-		let quick_actions = quick_actions
-			.into_iter()
-			.map(|(name, action)| {
-				let component =
-					Component::make(Button::new_with_handler(Some(name), "context-menu-quick-button", action));
-				component.mount_in(&quick_action_div);
-				component
-			})
-			.collect();
-		let actions = actions
-			.into_iter()
-			.map(|(name, action)| {
-				let component = Component::make(Button::new_with_handler(Some(name), "context-menu-button", action));
-				component.mount_in(&action_div);
-				component
-			})
-			.collect();
-
-		Self { element, quick_actions, actions }
+		Self::new_in(WebDom::shared(), quick_actions, actions, css).unwrap()
+	}
+}
+impl<D: Dom> ContextMenu<D> {
+	/// Like [new](ContextMenu::new), but in the dom (and returns an error instead of panicking).
+	pub fn new_in(
+		dom: Rc<D>,
+		quick_actions: Vec<MenuAction<D>>,
+		actions: Vec<MenuAction<D>>,
+		css: &str,
+	) -> Result<Self, ElementError> {
+		dom.register_default_style("ContextMenu", super::styles::CONTEXT_MENU);
+		let element = dom.create_styled("div", "context-menu")?;
+		dom.add_class(&element, css);
+		let listeners = DomListeners::new(dom.clone());
+		listeners.stop_bubbling(&element, &super::CONTROL_EVENTS);
+
+		let quick_action_div = dom.create_styled("div", "context-menu-quick-section")?;
+		dom.append_child(&element, &quick_action_div)?;
+		let action_div = dom.create_styled("div", "context-menu-section")?;
+		dom.append_child(&element, &action_div)?;
+
+		let make_buttons = |actions: Vec<MenuAction<D>>, css: &str, holder: &D::Node| {
+			actions
+				.into_iter()
+				.map(|(name, action)| {
+					let button = Button::new_in(dom.clone(), Some(name), css)?;
+					_ = button.on_click.set_handler(action);
+					let component = Component::make_in(dom.clone(), button);
+					component.mount_or_report(holder);
+					Ok((name, component))
+				})
+				.collect::<Result<Vec<_>, ElementError>>()
+		};
+		let quick_actions = make_buttons(quick_actions, "context-menu-quick-button", &quick_action_div)?;
+		let actions = make_buttons(actions, "context-menu-button", &action_div)?;
+
+		Ok(Self { element, quick_actions, actions, listeners })
+	}
+
+	/// Reports the failures of the buttons to the error handler (with the names of the actions as environment).
+	pub fn with_error_handler(self, error_handler: impl ComponentErrorHandler) -> Self {
+		for (name, button) in self.quick_actions.iter().chain(&self.actions) {
+			button.set_error_handler(error_handler.clone_for(*name));
+		}
+		self
 	}
 }
 
-impl ComponentContent for ContextMenu {
-	fn element(&self) -> &web_sys::Element {
+impl<D: Dom> ComponentContent<D> for ContextMenu<D> {
+	fn element(&self) -> &D::Node {
 		&self.element
 	}
+	fn destroy(&self) -> Result<()> {
+		self.listeners.clear();
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::cell::RefCell;
+
+	use super::*;
+	use crate::{common::Vector, dom::MemoryDom};
+
+	#[test]
+	fn runs_actions_without_bubbling() {
+		let dom = MemoryDom::new();
+		let root = dom.create_element("div").unwrap();
+		let bubbled = Rc::new(RefCell::new(Vec::new()));
+		for kind in ["click", "pointerdown"] {
+			dom.add_listener(&root, kind, {
+				let bubbled = bubbled.clone();
+				Box::new(move |event| bubbled.borrow_mut().push(event.kind.clone()))
+			});
+		}
+
+		let ran = Rc::new(RefCell::new(Vec::new()));
+		let action = |name: &'static str| -> MenuAction<MemoryDom> {
+			let ran = ran.clone();
+			(name, Box::new(move |_| ran.borrow_mut().push(name)))
+		};
+		let menu = ContextMenu::new_in(dom.clone(), vec![action("Copy")], vec![action("Delete")], "menu").unwrap();
+		let menu = Component::make_in(dom.clone(), menu);
+		assert!(menu.mount_or_report(&root));
+		assert_eq!(dom.text(&root), "CopyDelete");
+
+		let quick_button = dom.find_by_class(&root, "context-menu-quick-button")[0].clone();
+		let button = dom.find_by_class(&root, "context-menu-button")[0].clone();
+		dom.tap(&quick_button, Vector::zero());
+		dom.click(&button);
+		assert_eq!(*ran.borrow(), vec!["Copy", "Delete"]);
+		assert!(bubbled.borrow().is_empty());
+	}
 }
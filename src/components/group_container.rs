@@ -25,7 +25,9 @@ impl<F: GroupContainerElementFactory<N>, N> GroupContainer<F, N> {
 	}
 	pub fn add(&mut self, args: F::ConstructionArgs) -> Result<(), ()> {
 		let element = self.factory.make_new(&mut self.group, args);
-		element.mount_in(&self.element);
+		if !element.mount_or_report(&self.element) {
+			return Err(());
+		}
 		self.elements.push(element);
 		Ok(())
 	}
@@ -0,0 +1,303 @@
+use std::{
+	collections::{HashMap, HashSet},
+	hash::Hash,
+};
+
+use web_sys::{HtmlDivElement, Node};
+
+use crate::{
+	components::GroupContainerElementFactory,
+	elements::{div, styled},
+	events::Group,
+	Component, ComponentContent,
+};
+
+/// A [GroupContainerElementFactory] for items that have a key (see [KeyedList]).
+///
+/// The items are the construction args of the factory.
+pub trait KeyedListFactory<N = ()>: GroupContainerElementFactory<N> {
+	type Key: Eq + Hash + Clone;
+	/// The key of the item (must be unique within the list).
+	fn key(&self, item: &Self::ConstructionArgs) -> Self::Key;
+	/// Shows the new data of an item whose component is reused.
+	fn refresh(&mut self, _component: &Component<Self::Element>, _item: Self::ConstructionArgs) {}
+}
+
+/// A list of components that are identified by the keys of their items.
+///
+/// When the items change ([set_items](KeyedList::set_items)), the components of the keys that are still there are reused (and moved in the DOM if needed);
+/// only the components of new keys are created and only the ones of missing keys are dropped.
+///
+/// # Example
+/// ```text
+/// let mut layers = KeyedList::new("layer-list", LayerFactory);
+/// layers.set_items(document.layers.clone());
+/// ...
+/// layers.set_items(document.layers.clone()); // only changed layers are touched
+/// ```
+pub struct KeyedList<F: KeyedListFactory<N>, N = ()> {
+	element: HtmlDivElement,
+	factory: F,
+	entries: Entries<F::Key, Component<F::Element>>,
+	group: Group<N>,
+}
+
+impl<F: KeyedListFactory<N>, N> KeyedList<F, N> {
+	pub fn new(container_css: &str, factory: F) -> Self {
+		let element = styled(div(), container_css);
+		Self { element, factory, entries: Entries::new(), group: Group::new() }
+	}
+
+	/// Replaces the items of the list.
+	///
+	/// Reuses the components of the keys that are still there (and [refreshes](KeyedListFactory::refresh) them),
+	/// creates the ones for new keys and drops the others. Items with a key that came before are ignored.
+	pub fn set_items(&mut self, items: impl IntoIterator<Item = F::ConstructionArgs>) -> Result<(), ()> {
+		let items: Vec<_> = items.into_iter().map(|item| (self.factory.key(&item), item)).collect();
+		let removed = self.entries.replace(items, |item, existing| match existing {
+			Some(component) => {
+				self.factory.refresh(&component, item);
+				Some(component)
+			},
+			None => {
+				let component = self.factory.make_new(&mut self.group, item);
+				component.mount_or_report(&self.element).then_some(component)
+			},
+		});
+		// the components of the keys that are gone
+		drop(removed);
+
+		self.reorder()
+	}
+
+	/// Inserts the item at the index (or at the end if the index is too large).
+	///
+	/// If there already is an item with the key, it is refreshed and moved there instead.
+	pub fn insert(&mut self, index: usize, item: F::ConstructionArgs) -> Result<(), ()> {
+		let key = self.factory.key(&item);
+		let component = match self.entries.take(&key) {
+			Some(component) => {
+				self.factory.refresh(&component, item);
+				component
+			},
+			None => {
+				let component = self.factory.make_new(&mut self.group, item);
+				if !component.mount_or_report(&self.element) {
+					return Err(());
+				}
+				component
+			},
+		};
+		self.entries.insert(index, key, component);
+		self.reorder()
+	}
+	/// Adds the item at the end (see [insert](KeyedList::insert)).
+	pub fn push(&mut self, item: F::ConstructionArgs) -> Result<(), ()> {
+		self.insert(self.entries.len(), item)
+	}
+	/// Drops the component of the key.
+	///
+	/// # Returns
+	/// Whether there was one.
+	pub fn remove(&mut self, key: &F::Key) -> bool {
+		self.entries.take(key).is_some()
+	}
+	/// Moves the component of the key to the index (or to the end if the index is too large).
+	///
+	/// # Returns
+	/// Whether there is a component with the key.
+	pub fn move_to(&mut self, key: &F::Key, index: usize) -> Result<bool, ()> {
+		if !self.entries.move_to(key, index) {
+			return Ok(false);
+		}
+		self.reorder()?;
+		Ok(true)
+	}
+	/// Drops all components.
+	pub fn clear(&mut self) {
+		self.entries.list.clear();
+	}
+
+	pub fn get(&self, key: &F::Key) -> Option<&Component<F::Element>> {
+		self.entries.get(key)
+	}
+	pub fn position(&self, key: &F::Key) -> Option<usize> {
+		self.entries.position(key)
+	}
+	pub fn keys(&self) -> impl Iterator<Item = &F::Key> {
+		self.entries.keys()
+	}
+	pub fn iter(&self) -> impl Iterator<Item = (&F::Key, &Component<F::Element>)> {
+		self.entries.list.iter().map(|(key, component)| (key, component))
+	}
+	pub fn len(&self) -> usize {
+		self.entries.len()
+	}
+	pub fn is_empty(&self) -> bool {
+		self.entries.list.is_empty()
+	}
+
+	/// Moves the elements of the components into the order of the entries (only the ones that are out of place).
+	fn reorder(&self) -> Result<(), ()> {
+		let mut expected: Option<Node> = self.element.first_child();
+		for (_, component) in &self.entries.list {
+			let node: &Node = component.element();
+			match &expected {
+				Some(current) if current.is_same_node(Some(node)) => expected = current.next_sibling(),
+				_ => {
+					self.element.insert_before(node, expected.as_ref()).or(Err(()))?;
+				},
+			}
+		}
+		Ok(())
+	}
+}
+
+impl<F: KeyedListFactory<N>, N> ComponentContent for KeyedList<F, N> {
+	fn element(&self) -> &web_sys::Element {
+		&self.element
+	}
+}
+
+/// The ordered values of a [KeyedList] by key (apart from the DOM).
+struct Entries<K, V> {
+	list: Vec<(K, V)>,
+}
+
+impl<K: Eq + Hash + Clone, V> Entries<K, V> {
+	fn new() -> Self {
+		Self { list: Vec::new() }
+	}
+
+	/// Replaces the entries with the ones of the items (in their order).
+	///
+	/// `place` gets every item with the value of its key (if there was one) and returns the value to keep
+	/// (or `None` to leave the item out). Items with a key that came before are ignored.
+	///
+	/// # Returns
+	/// The values of the keys that are gone.
+	fn replace<T>(
+		&mut self,
+		items: impl IntoIterator<Item = (K, T)>,
+		mut place: impl FnMut(T, Option<V>) -> Option<V>,
+	) -> Vec<V> {
+		let mut existing: HashMap<K, V> = self.list.drain(..).collect();
+		let mut seen = HashSet::new();
+		for (key, item) in items {
+			if !seen.insert(key.clone()) {
+				continue;
+			}
+			if let Some(value) = place(item, existing.remove(&key)) {
+				self.list.push((key, value));
+			}
+		}
+		existing.into_values().collect()
+	}
+	/// Inserts the value at the index (or at the end if the index is too large).
+	fn insert(&mut self, index: usize, key: K, value: V) {
+		let index = index.min(self.list.len());
+		self.list.insert(index, (key, value));
+	}
+	/// Removes the entry of the key.
+	fn take(&mut self, key: &K) -> Option<V> {
+		let position = self.position(key)?;
+		Some(self.list.remove(position).1)
+	}
+	/// Moves the entry of the key to the index (or to the end if the index is too large).
+	///
+	/// # Returns
+	/// Whether there is an entry with the key.
+	fn move_to(&mut self, key: &K, index: usize) -> bool {
+		let Some(position) = self.position(key) else { return false };
+		let entry = self.list.remove(position);
+		let index = index.min(self.list.len());
+		self.list.insert(index, entry);
+		true
+	}
+
+	fn get(&self, key: &K) -> Option<&V> {
+		self.list.iter().find(|(existing, _)| existing == key).map(|(_, value)| value)
+	}
+	fn position(&self, key: &K) -> Option<usize> {
+		self.list.iter().position(|(existing, _)| existing == key)
+	}
+	fn keys(&self) -> impl Iterator<Item = &K> {
+		self.list.iter().map(|(key, _)| key)
+	}
+	fn len(&self) -> usize {
+		self.list.len()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// Entries whose values are `(id, data)` where the id tells which call of `place` created the value.
+	fn replace(
+		entries: &mut Entries<char, (usize, u32)>,
+		next_id: &mut usize,
+		items: &[(char, u32)],
+	) -> Vec<usize> {
+		let removed = entries.replace(items.iter().copied(), |data, existing| match existing {
+			Some((id, _)) => Some((id, data)),
+			None => {
+				*next_id += 1;
+				Some((*next_id, data))
+			},
+		});
+		let mut removed: Vec<usize> = removed.into_iter().map(|(id, _)| id).collect();
+		removed.sort();
+		removed
+	}
+	fn keys(entries: &Entries<char, (usize, u32)>) -> String {
+		entries.keys().collect()
+	}
+
+	#[test]
+	fn replace_reuses_values_of_kept_keys() {
+		let mut entries = Entries::new();
+		let mut next_id = 0;
+		assert!(replace(&mut entries, &mut next_id, &[('a', 1), ('b', 2), ('c', 3)]).is_empty());
+		assert_eq!(keys(&entries), "abc");
+
+		let removed = replace(&mut entries, &mut next_id, &[('c', 30), ('d', 4), ('a', 10)]);
+		assert_eq!(keys(&entries), "cda");
+		// b is gone, c and a keep their values (with the new data) and d is new
+		assert_eq!(removed, vec![2]);
+		assert_eq!(entries.get(&'c'), Some(&(3, 30)));
+		assert_eq!(entries.get(&'a'), Some(&(1, 10)));
+		assert_eq!(entries.get(&'d'), Some(&(4, 4)));
+	}
+
+	#[test]
+	fn replace_skips_duplicates_and_refused_items() {
+		let mut entries = Entries::new();
+		let items = [('a', 1), ('b', 2), ('a', 3), ('c', 4)];
+		let removed = entries.replace(items, |data, _| (data != 4).then_some(data));
+		assert!(removed.is_empty());
+		assert_eq!(entries.keys().collect::<String>(), "ab");
+		assert_eq!(entries.get(&'a'), Some(&1));
+	}
+
+	#[test]
+	fn insert_move_and_take_keep_the_order() {
+		let mut entries = Entries::new();
+		entries.insert(0, 'b', 2);
+		entries.insert(0, 'a', 1);
+		entries.insert(10, 'c', 3);
+		assert_eq!(entries.keys().collect::<String>(), "abc");
+
+		assert!(entries.move_to(&'a', 1));
+		assert_eq!(entries.keys().collect::<String>(), "bac");
+		assert!(entries.move_to(&'b', 10));
+		assert_eq!(entries.keys().collect::<String>(), "acb");
+		assert!(!entries.move_to(&'x', 0));
+
+		assert_eq!(entries.take(&'c'), Some(3));
+		assert_eq!(entries.take(&'c'), None);
+		assert_eq!(entries.keys().collect::<String>(), "ab");
+		assert_eq!(entries.position(&'b'), Some(1));
+		assert_eq!(entries.len(), 2);
+	}
+}
@@ -1,24 +1,33 @@
-use web_sys::HtmlLabelElement;
+use std::rc::Rc;
 
 use crate::{
-	elements::{label, styled},
+	dom::{Dom, WebDom},
+	errors::ElementError,
 	ComponentContent,
 };
 
-pub struct Label {
-	root: HtmlLabelElement,
+pub struct Label<D: Dom = WebDom> {
+	dom: Rc<D>,
+	root: D::Node,
 }
 impl Label {
 	pub fn new(text: &str, css: &str) -> Self {
-		let root = styled(label(text), css);
-		Self { root }
+		Self::new_in(WebDom::shared(), text, css).unwrap()
+	}
+}
+impl<D: Dom> Label<D> {
+	/// Like [new](Label::new), but in the dom (and returns an error instead of panicking).
+	pub fn new_in(dom: Rc<D>, text: &str, css: &str) -> Result<Self, ElementError> {
+		let root = dom.create_styled("label", css)?;
+		dom.set_text(&root, text);
+		Ok(Self { dom, root })
 	}
 	pub fn set_text(&self, text: Option<&str>) {
-		self.root.set_text_content(text);
+		self.dom.set_text(&self.root, text.unwrap_or_default());
 	}
 }
-impl ComponentContent for Label {
-	fn element(&self) -> &web_sys::Element {
+impl<D: Dom> ComponentContent<D> for Label<D> {
+	fn element(&self) -> &D::Node {
 		&self.root
 	}
 }
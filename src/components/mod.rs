@@ -1,3 +1,8 @@
+pub mod styles;
+
+/// The events that don't bubble up from the controls (so e.g. clicking a button doesn't also click what's below it).
+const CONTROL_EVENTS: [&str; 5] = ["click", "pointerdown", "pointermove", "pointerup", "contextmenu"];
+
 mod label;
 pub use label::*;
 
@@ -25,5 +30,8 @@ pub use side_bar::*;
 mod group_container;
 pub use group_container::*;
 
+mod keyed_list;
+pub use keyed_list::*;
+
 mod selection_frame;
 pub use selection_frame::*;
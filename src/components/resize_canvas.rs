@@ -1,4 +1,4 @@
-use std::rc::Rc;
+use std::rc::{Rc, Weak};
 
 use anyhow::Result;
 use wasm_bindgen::{prelude::Closure, JsCast};
@@ -9,28 +9,31 @@ use crate::{elements::*, events::CustomEventListener, ComponentContent};
 pub struct ResizeCanvas {
 	pub canvas: HtmlCanvasElement,
 	pub on_resize: CustomEventListener<(u32, u32)>,
+	/// observes the canvas while it is mounted
+	resizer: ResizeObserver,
+	_callback: Closure<dyn FnMut()>,
 }
 
 impl ResizeCanvas {
 	pub fn new(css: &'static str) -> Rc<Self> {
 		let canvas = styled(canvas(), css);
 
-		let this = Rc::new(Self { canvas, on_resize: CustomEventListener::new() });
+		Rc::new_cyclic(|this: &Weak<Self>| {
+			let on_resize = {
+				let this = this.clone();
+				move || {
+					let Some(this) = this.upgrade() else { return };
+					let (w, h) = this.size();
+					this.set_context_size(w, h);
+					this.on_resize.fire((w, h));
+				}
+			};
 
-		let on_resize = {
-			let this = this.clone();
-			move || {
-				let (w, h) = this.size();
-				this.set_context_size(w, h);
-				this.on_resize.fire((w, h));
-			}
-		};
+			let callback = Closure::<dyn FnMut()>::new(on_resize);
+			let resizer = ResizeObserver::new(callback.as_ref().unchecked_ref()).unwrap();
 
-		let callback = Closure::<dyn FnMut()>::new(on_resize);
-		let resizer = ResizeObserver::new(callback.into_js_value().unchecked_ref()).unwrap();
-		resizer.observe(this.element());
-
-		this
+			Self { canvas, on_resize: CustomEventListener::new(), resizer, _callback: callback }
+		})
 	}
 
 	pub fn size(&self) -> (u32, u32) {
@@ -48,6 +51,15 @@ impl ComponentContent for ResizeCanvas {
 		&self.canvas
 	}
 
+	fn mounted(&self) -> Result<()> {
+		self.resizer.observe(&self.canvas);
+		Ok(())
+	}
+	fn before_unmount(&self) -> Result<()> {
+		self.resizer.disconnect();
+		Ok(())
+	}
+
 	fn update(&self) -> Result<()> {
 		let (w, h) = self.size();
 		self.set_context_size(w, h);
@@ -56,6 +68,7 @@ impl ComponentContent for ResizeCanvas {
 	}
 
 	fn destroy(&self) -> Result<()> {
+		self.resizer.disconnect();
 		self.on_resize.remove_handler();
 		Ok(())
 	}
@@ -1,35 +1,48 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{
+	cell::{Cell, RefCell},
+	rc::Rc,
+};
 
 use anyhow::{anyhow, Result};
-use ncollide2d::na::{convert, Affine2, Scale2, Translation2};
 use result_or_err::ResultOrErr;
 use wasm_bindgen::JsCast;
 use web_sys::{HtmlCanvasElement, HtmlDivElement, HtmlElement, PointerEvent};
 
 use crate::{
-	common::{Bounds, Number, Vector},
-	elements::*,
+	common::{
+		Bounds, Number, Screen, ScreenVector, SnapLine, Snapper, SpaceTransform, Transform, Vector, World,
+		WorldBounds, WorldVector,
+	},
+	elements::{Length, Style, StyleSheets},
+	errors::{ConsoleErrorHandler, ErrorHandler, StyleError},
 	events::{CustomEventListener, SharedEventListener},
 	ComponentContent,
 };
 
-pub struct SelectionFrame {
+pub struct SelectionFrame<H: ErrorHandler<StyleError> + 'static = ConsoleErrorHandler> {
 	/// a function that defines the selections bounds
 	get_bounds: Box<dyn Fn() -> Option<Bounds>>,
 	/// a function that lets the user render stuff to the selection
-	integrate_transformation: Box<dyn Fn(Affine2<Number>, &Self) -> Result<(), ()>>,
+	integrate_transformation: Box<dyn Fn(Transform, &Self) -> Result<(), ()>>,
 	pub on_click: CustomEventListener<PointerEvent>,
 	pub on_context: CustomEventListener<PointerEvent>,
+	/// fired while moving with the lines the selection snapped to (and with no lines once moving ends)
+	pub on_snap: CustomEventListener<Vec<SnapLine>>,
 	pub outline: HtmlDivElement,
 	pub control_knob: HtmlDivElement,
 
 	// State
-	bounds: RefCell<Option<Bounds>>,
-	translation: RefCell<Vector>,
+	bounds: RefCell<Option<WorldBounds>>,
+	translation: RefCell<WorldVector>,
 	scale: RefCell<Number>,
 	transformation_locked: RefCell<bool>, // to prevent overlapping translate and scale actions
 	integrate_on_move: RefCell<bool>,
+	snapper: RefCell<Option<Snapper>>,
+	/// converts pointer positions into the coordinates of the selection
+	screen_to_world: Cell<SpaceTransform<Screen, World>>,
 	pub on_render: CustomEventListener<()>,
+	/// reports failures to position the frame
+	error_handler: H,
 
 	// UI
 	parent: HtmlElement,
@@ -47,20 +60,32 @@ pub struct SelectionFrame {
 	resize_up_listener: SharedEventListener<PointerEvent>,
 }
 
-impl SelectionFrame {
+impl<H: ErrorHandler<StyleError> + 'static> SelectionFrame<H> {
+	/// The bounds of the selection are in [World] coordinates,
+	/// which are also the css pixels the frame is positioned with in the parent.
+	/// Pointer positions are converted to those with the [screen to world transform](SelectionFrame::set_screen_to_world).
+	///
+	/// The error handler reports the failures to position the frame.
 	pub fn new(
 		parent: HtmlElement,
 		compute_bonds: impl Fn() -> Option<Bounds> + 'static,
-		integrate_transformation: impl Fn(Affine2<Number>, &Self) -> Result<(), ()> + 'static,
+		integrate_transformation: impl Fn(Transform, &Self) -> Result<(), ()> + 'static,
+		error_handler: H,
 	) -> Rc<Self> {
-		let element = styled(div(), "selection-frame");
-
-		let canvas = on(&element, styled(canvas(), "selection-canvas"));
-		let outline = on(&element, styled(div(), "selection-outline"));
-
-		let border = on(&outline, styled(div(), "selection-border"));
+		_ = StyleSheets::register_default("SelectionFrame", super::styles::SELECTION_FRAME);
+		crate::html! { _listeners;
+			div() as element [class("selection-frame")] {
+				canvas() as canvas [class("selection-canvas")]
+				div() as outline [class("selection-outline")] {
+					div() [class("selection-border")]
+					div() as resize_button [class("resize-button")]
+					div() as control_knob [class("control-knob")] {
+						div() as move_button [class("move-button")]
+					}
+				}
+			}
+		}
 
-		let resize_button = on(&outline, styled(div(), "resize-button"));
 		let resize_down_listener =
 			SharedEventListener::<PointerEvent>::new(resize_button.clone().into(), "pointerdown");
 		let resize_move_listener =
@@ -68,8 +93,6 @@ impl SelectionFrame {
 		let resize_up_listener =
 			SharedEventListener::<PointerEvent>::new(resize_button.clone().into(), "pointerup");
 
-		let control_knob = on(&outline, styled(div(), "control-knob"));
-		let move_button = on(&control_knob, styled(div(), "move-button"));
 		let move_down_listener =
 			SharedEventListener::<PointerEvent>::new(move_button.clone().into(), "pointerdown");
 		let move_move_listener =
@@ -87,12 +110,16 @@ impl SelectionFrame {
 			control_knob,
 			on_click,
 			on_context,
+			on_snap: CustomEventListener::new(),
 
-			translation: RefCell::new(Vector::zero()),
+			translation: RefCell::new(WorldVector::zero()),
 			scale: RefCell::new(1.0),
 			transformation_locked: RefCell::new(false),
 			integrate_on_move: RefCell::new(false),
+			snapper: RefCell::new(None),
+			screen_to_world: Cell::new(SpaceTransform::identity()),
 			on_render: CustomEventListener::new(),
+			error_handler,
 
 			bounds: RefCell::new(None),
 
@@ -130,21 +157,24 @@ impl SelectionFrame {
 
 				this.move_move_listener.set_handler({
 					let this = this.clone();
-					let mut start = start.clone();
+					let mut start = start;
 					move |event: PointerEvent| {
 						event.prevent_default();
 						event.stop_propagation();
 
 						let end = this.capture_position(&event).unwrap();
-						let drag = end - start.clone();
+						let drag = this.snap_drag(this.drag_in_world(end - start));
 						this.set_translation(drag);
 						if let Ok(integrate) = this.integrate_on_move.try_borrow() {
 							if *integrate {
-								start = end;
+								// keep the offset between pointer and selection that snapping introduced
+								if let Some(world_to_screen) = this.screen_to_world.get().inverse() {
+									start = start + world_to_screen.apply_to_direction(drag);
+								}
 								this.integrate_transformation();
 							}
 						}
-						this.reposition().unwrap();
+						_ = this.reposition();
 					}
 				});
 
@@ -159,10 +189,12 @@ impl SelectionFrame {
 							integrate = *i;
 						}
 						if !integrate {
-							let drag = this.capture_position(&event).unwrap() - start.clone();
+							let end = this.capture_position(&event).unwrap();
+							let drag = this.snap_drag(this.drag_in_world(end - start));
 							this.set_translation(drag);
 							this.integrate_transformation();
 						}
+						_ = this.on_snap.fire(Vec::new());
 						this.reposition();
 						this.rerender();
 						this.unlock_transformation();
@@ -235,7 +267,7 @@ impl SelectionFrame {
 		// 	}
 		// });
 
-		this.reposition().unwrap();
+		_ = this.reposition();
 		this.rerender().unwrap();
 
 		this
@@ -250,8 +282,24 @@ impl SelectionFrame {
 		*integrate = value;
 	}
 
-	fn selection(&self) -> Result<Option<Bounds>, ()> {
-		Ok((self.get_bounds)())
+	/// Sets how pointer positions (on the screen) are converted into the coordinates of the selection.
+	///
+	/// That's the inverse of the transformation the parent is displayed with (e.g. its zoom);
+	/// the identity by default.
+	pub fn set_screen_to_world(&self, transform: SpaceTransform<Screen, World>) {
+		self.screen_to_world.set(transform);
+	}
+
+	/// Use the snapper to snap the selection while moving it (or stop snapping with `None`).
+	///
+	/// The snapper works in the same coordinates as the selection bounds.
+	pub fn set_snapper(&self, snapper: Option<Snapper>) {
+		let Ok(mut current) = self.snapper.try_borrow_mut() else { return };
+		*current = snapper;
+	}
+
+	fn selection(&self) -> Result<Option<WorldBounds>, ()> {
+		Ok((self.get_bounds)().map(|bounds| bounds.retagged()))
 	}
 
 	fn lock_transformation(&self) -> bool {
@@ -268,12 +316,30 @@ impl SelectionFrame {
 		};
 	}
 
-	fn set_translation(&self, total_drag: Vector) -> Result<(), ()> {
+	/// Snaps the selection moved by `drag` (if there is a snapper) and reports the lines it snapped to via `on_snap`.
+	///
+	/// Returns the snapped drag.
+	fn snap_drag(&self, drag: WorldVector) -> WorldVector {
+		let Ok(Some(bounds)) = self.selection() else { return drag };
+		let snapped = {
+			let Ok(snapper) = self.snapper.try_borrow() else { return drag };
+			let Some(snapper) = snapper.as_ref() else { return drag };
+			snapper.snap_bounds(&bounds.translated(drag).retagged())
+		};
+		_ = self.on_snap.fire(snapped.lines);
+		drag + snapped.offset.retagged()
+	}
+	/// The drag of the pointer in the coordinates of the selection.
+	fn drag_in_world(&self, drag: ScreenVector) -> WorldVector {
+		self.screen_to_world.get().apply_to_direction(drag)
+	}
+
+	fn set_translation(&self, total_drag: WorldVector) -> Result<(), ()> {
 		let mut translation = self.translation.try_borrow_mut().or_err(())?;
 		*translation = total_drag;
 		Ok(())
 	}
-	fn set_scale(&self, total_drag: Vector) -> Result<(), ()> {
+	fn set_scale(&self, total_drag: WorldVector) -> Result<(), ()> {
 		let bounds = self.selection()?.ok_or(())?;
 
 		let scale_x = Number::max((total_drag.x + bounds.size().x) / bounds.size().x, 0.0);
@@ -284,7 +350,7 @@ impl SelectionFrame {
 		Ok(())
 	}
 	fn reset_transformation(&self) -> Result<(), ()> {
-		*self.translation.try_borrow_mut().or_err(())? = Vector::zero();
+		*self.translation.try_borrow_mut().or_err(())? = WorldVector::zero();
 		*self.scale.try_borrow_mut().or_err(())? = 1.0;
 		Ok(())
 	}
@@ -293,14 +359,11 @@ impl SelectionFrame {
 			let translation = self.translation.try_borrow_mut().or_err(())?;
 			let scale = self.scale.try_borrow_mut().or_err(())?;
 			let bounds = self.selection()?.ok_or(())?;
-			let start = Translation2::new(bounds.start().x, bounds.start().y);
-			start
-				* convert::<_, Affine2<_>>(Scale2::new(*scale, *scale))
-				* start.inverse()
-				* Translation2::new(translation.x, translation.y)
+			let (start, translation): (Vector, Vector) = (bounds.start().retagged(), translation.retagged());
+			Transform::scale_around(start, Vector::new_square(*scale)) * Transform::translation(translation)
 		};
 
-		if transformation.try_inverse().is_none() {
+		if transformation.inverse().is_none() {
 			return Err(());
 		};
 
@@ -309,8 +372,11 @@ impl SelectionFrame {
 		self.reset_transformation()
 	}
 
-	fn capture_position(&self, event: &PointerEvent) -> Result<Vector, ()> {
-		Ok(Vector::new(event.client_x() as f64, event.client_y() as f64))
+	/// The pointer position in screen coordinates.
+	///
+	/// Differences between those positions need to be converted to world coordinates (see [SelectionFrame::drag_in_world]).
+	fn capture_position(&self, event: &PointerEvent) -> Result<ScreenVector, ()> {
+		Ok(ScreenVector::new(event.client_x() as f64, event.client_y() as f64))
 	}
 
 	/// Adjusts position and size of this component to match display the current selection.
@@ -330,12 +396,15 @@ impl SelectionFrame {
 		let size = bounds.size().clone() * *scale;
 
 		// move canvas into position
-		self.element.style().set_property("left", format!("{}px", start.x).as_str());
-		self.element.style().set_property("top", format!("{}px", start.y).as_str());
-		self.element.style().set_property("width", format!("{}px", size.x).as_str());
-		self.element.style().set_property("height", format!("{}px", size.y).as_str());
-
-		Ok(())
+		let style = Style::new()
+			.left(Length::Px(start.x))
+			.top(Length::Px(start.y))
+			.width(Length::Px(size.x))
+			.height(Length::Px(size.y));
+		match style.apply(&self.element, &self.error_handler) {
+			true => Ok(()),
+			false => Err(()),
+		}
 	}
 	/// Rerenders this compontents content (the selection).
 	pub fn rerender(&self) -> Result<(), ()> {
@@ -372,7 +441,7 @@ impl SelectionFrame {
 	}
 }
 
-impl ComponentContent for SelectionFrame {
+impl<H: ErrorHandler<StyleError> + 'static> ComponentContent for SelectionFrame<H> {
 	fn element(&self) -> &web_sys::Element {
 		&self.element
 	}
@@ -1,33 +1,51 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{
+	cell::{Cell, RefCell},
+	rc::Rc,
+};
 
-use result_or_err::ResultOrErr;
-use wasm_bindgen::JsCast;
-use web_sys::{Element, HtmlDivElement, HtmlElement, PointerEvent};
+use web_sys::Element;
 
 use crate::{
+	component::Report,
 	components::Label,
-	elements::{div, hr, on, styled},
-	events::SharedEventListener,
+	dom::{Dom, DomListeners, WebDom},
+	errors::{ComponentErrorHandler, ElementError},
 	Component, ComponentContent, DynamicComponent,
 };
 
 use super::Button;
 
-pub struct SideBar {
-	parent: RefCell<Element>,
-	element: HtmlDivElement,
-	content_holder: HtmlDivElement,
-
-	x_button: Component<Button>,
-	title: Component<Label>,
-	content: RefCell<Option<Box<dyn DynamicComponent>>>,
+pub struct SideBar<D: Dom = WebDom> {
+	dom: Rc<D>,
+	parent: RefCell<D::Node>,
+	element: D::Node,
+	content_holder: D::Node,
+
+	x_button: Component<Button<D>, D>,
+	title: Component<Label<D>, D>,
+	content: RefCell<Option<Box<dyn DynamicComponent<D>>>>,
+
+	listeners: DomListeners<D>,
+	/// the pointer position (x) and the width when resizing started
+	resize_start: Cell<Option<(f64, f64)>>,
+	/// reports failures of the content (the console if `None`)
+	content_error_handler: RefCell<Option<Report>>,
+}
 
-	resize_down_listener: SharedEventListener<PointerEvent>,
-	resize_move_listener: SharedEventListener<PointerEvent>,
-	resize_up_listener: SharedEventListener<PointerEvent>,
+/// The css classes of the parts of a [SideBar] (in addition to their default classes).
+#[derive(Clone, Copy, Default, Debug)]
+pub struct SideBarCss<'a> {
+	pub side_bar: &'a str,
+	pub handle: &'a str,
+	pub internal: &'a str,
+	pub controls: &'a str,
+	pub button: &'a str,
+	pub title: &'a str,
+	pub content_holder: &'a str,
 }
 
 impl SideBar {
+	/// Failures of the controls and of the content go to the console (see [with_error_handler](SideBar::with_error_handler)).
 	pub fn new(
 		parent: Element,
 		sidebar_css: &str,
@@ -38,26 +56,44 @@ impl SideBar {
 		title_css: &str,
 		content_holder_css: &str,
 	) -> Rc<Self> {
-		let element = styled(div(), sidebar_css);
-
-		let resize_handle = on(&element, styled(div(), handle_css));
-		let internal = on(&element, styled(div(), internal_css));
-		let controls = on(&internal, styled(div(), controls_css));
-		let content_holder = on(&internal, styled(div(), content_holder_css));
-
-		let resize_down_listener =
-			SharedEventListener::<PointerEvent>::new(resize_handle.clone().into(), "pointerdown");
-		let resize_move_listener =
-			SharedEventListener::<PointerEvent>::new(resize_handle.clone().into(), "pointermove");
-		let resize_up_listener =
-			SharedEventListener::<PointerEvent>::new(resize_handle.clone().into(), "pointerup");
+		let css = SideBarCss {
+			side_bar: sidebar_css,
+			handle: handle_css,
+			internal: internal_css,
+			controls: controls_css,
+			button: button_css,
+			title: title_css,
+			content_holder: content_holder_css,
+		};
+		Self::new_in(WebDom::shared(), parent, css).unwrap()
+	}
+}
 
-		let x_button = Component::make(Button::new(None, button_css));
-		x_button.mount_in(&controls).or_err(()).unwrap();
-		let title = Component::make(Label::new("", title_css));
-		title.mount_in(&controls);
+impl<D: Dom> SideBar<D> {
+	/// Like [new](SideBar::new), but in the dom (and returns an error instead of panicking).
+	pub fn new_in(dom: Rc<D>, parent: D::Node, css: SideBarCss) -> Result<Rc<Self>, ElementError> {
+		dom.register_default_style("SideBar", super::styles::SIDE_BAR);
+		let create_in = |parent: &D::Node, class: &str, css: &str| -> Result<D::Node, ElementError> {
+			let element = dom.create_styled("div", class)?;
+			dom.add_class(&element, css);
+			dom.append_child(parent, &element)?;
+			Ok(element)
+		};
+		let element = dom.create_styled("div", "side-bar")?;
+		dom.add_class(&element, css.side_bar);
+
+		let resize_handle = create_in(&element, "side-bar-handle", css.handle)?;
+		let internal = create_in(&element, "side-bar-internal", css.internal)?;
+		let controls = create_in(&internal, "side-bar-controls", css.controls)?;
+		let content_holder = create_in(&internal, "side-bar-content", css.content_holder)?;
+
+		let x_button = Component::make_in(dom.clone(), Button::new_in(dom.clone(), None, css.button)?);
+		x_button.mount_or_report(&controls);
+		let title = Component::make_in(dom.clone(), Label::new_in(dom.clone(), "", css.title)?);
+		title.mount_or_report(&controls);
 
 		let this = Rc::new(Self {
+			dom: dom.clone(),
 			parent: RefCell::new(parent),
 			element,
 			content_holder,
@@ -66,77 +102,89 @@ impl SideBar {
 			title,
 			content: RefCell::new(None),
 
-			resize_down_listener,
-			resize_move_listener,
-			resize_up_listener,
+			listeners: DomListeners::new(dom),
+			resize_start: Cell::new(None),
+			content_error_handler: RefCell::new(None),
 		});
 
-		this.resize_down_listener.set_handler({
+		this.listeners.add(&resize_handle, "pointerdown", {
 			let this = this.clone();
-			move |event: PointerEvent| {
+			let resize_handle = resize_handle.clone();
+			move |event| {
 				event.prevent_default();
 				event.stop_propagation();
 
-				if event.buttons() != 1 {
+				if event.buttons != 1 {
 					return;
 				};
+				let Some(position) = event.position else { return };
+
+				if let Some(pointer_id) = event.pointer_id {
+					this.dom.capture_pointer(&resize_handle, pointer_id);
+				}
+				this.resize_start.set(Some((position.x, this.dom.size(&this.element).x)));
+			}
+		});
+		this.listeners.add(&resize_handle, "pointermove", {
+			let this = this.clone();
+			move |event| {
+				event.prevent_default();
+				event.stop_propagation();
 
-				event.target().unwrap().dyn_into::<HtmlElement>().unwrap().set_pointer_capture(event.pointer_id());
-
-				let width = this.element.client_width();
-				let start = event.client_x();
-
-				this.resize_move_listener.set_handler({
-					let this = this.clone();
-					let width = width.clone();
-					let start = start.clone();
-					move |event: PointerEvent| {
-						event.prevent_default();
-						event.stop_propagation();
-
-						let diff = event.client_x() - start;
-						this.element.style().set_property("width", format!("{}px", width - diff).as_str());
-					}
-				});
-
-				this.resize_up_listener.set_handler({
-					let this = this.clone();
-					move |event: PointerEvent| {
-						event.prevent_default();
-						event.stop_propagation();
-
-						this.resize_move_listener.remove_handler();
-						this.resize_up_listener.remove_handler();
-					}
-				});
+				let (Some((start, width)), Some(position)) = (this.resize_start.get(), event.position) else { return };
+				let diff = position.x - start;
+				_ = this.dom.set_style(&this.element, "width", &format!("{}px", width - diff));
 			}
 		});
+		this.listeners.add(&resize_handle, "pointerup", {
+			let this = this.clone();
+			move |event| {
+				event.prevent_default();
+				event.stop_propagation();
 
-		this.x_button.on_click.set_handler({
+				this.resize_start.set(None);
+			}
+		});
+
+		_ = this.x_button.on_click.set_handler({
 			let this = this.clone();
 			move |_| {
 				this.close();
 			}
 		});
 
-		this
+		Ok(this)
 	}
 
-	pub fn open(&self, content: impl ComponentContent + 'static) {
+	/// Reports the failures of the controls and of the content to the error handler (instead of the console).
+	pub fn with_error_handler(self: Rc<Self>, error_handler: impl ComponentErrorHandler) -> Rc<Self> {
+		self.x_button.set_error_handler(error_handler.clone_for("close"));
+		self.title.set_error_handler(error_handler.clone_for("title"));
+		let content_error_handler = error_handler.clone_for("content");
+		if let Ok(mut handler) = self.content_error_handler.try_borrow_mut() {
+			*handler = Some(Rc::new(move |error| content_error_handler.handle(error)));
+		}
+		self
+	}
+
+	pub fn open(&self, content: impl ComponentContent<D> + 'static) {
 		let Ok(parent) = self.parent.try_borrow() else { return };
 		let Ok(mut c) = self.content.try_borrow_mut() else { return };
 
-		let component = Component::make(content);
-		component.mount_in(&self.content_holder);
+		let component = Component::make_in(self.dom.clone(), content);
+		if let Ok(Some(report)) = self.content_error_handler.try_borrow().as_deref() {
+			component.set_report(report.clone());
+		}
+		component.mount_or_report(&self.content_holder);
 		*c = Some(Box::new(component));
 
-		parent.append_child(&self.element);
+		_ = self.dom.append_child(&parent, &self.element);
 	}
 
 	pub fn close(&self) {
 		let Ok(mut content) = self.content.try_borrow_mut() else { return };
 		*content = None;
-		self.element.remove();
+		self.dom.remove(&self.element);
 	}
 
 	pub fn set_title(&self, text: Option<&str>) {
@@ -144,13 +192,52 @@ impl SideBar {
 	}
 }
 
-impl ComponentContent for SideBar {
-	fn element(&self) -> &web_sys::Element {
+impl<D: Dom> ComponentContent<D> for SideBar<D> {
+	fn element(&self) -> &D::Node {
 		&self.element
 	}
-	fn mount_in(&self, element: &Element) -> anyhow::Result<()> {
+	fn mount_in(&self, element: &D::Node) -> anyhow::Result<()> {
 		let mut parent = self.parent.try_borrow_mut()?;
 		*parent = element.clone();
 		Ok(())
 	}
+	fn destroy(&self) -> anyhow::Result<()> {
+		self.listeners.clear();
+		self.x_button.on_click.remove_handler();
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		common::Vector,
+		dom::{DomEvent, MemoryDom},
+	};
+
+	#[test]
+	fn opens_resizes_and_closes() {
+		let dom = MemoryDom::new();
+		let root = dom.create_element("div").unwrap();
+		let side_bar = SideBar::new_in(dom.clone(), root.clone(), SideBarCss::default()).unwrap();
+
+		side_bar.set_title(Some("Layers"));
+		side_bar.open(Label::new_in(dom.clone(), "Content", "").unwrap());
+		assert_eq!(dom.children(&root), vec![side_bar.element.clone()]);
+		assert_eq!(dom.text(&root), "LayersContent");
+
+		dom.set_style(&side_bar.element, "width", "200px").unwrap();
+		let handle = dom.find_by_class(&root, "side-bar-handle")[0].clone();
+		dom.dispatch(&handle, &DomEvent::pointer("pointerdown", Vector::new(100.0, 0.0), 1));
+		dom.dispatch(&handle, &DomEvent::pointer("pointermove", Vector::new(70.0, 0.0), 1));
+		dom.dispatch(&handle, &DomEvent::pointer("pointerup", Vector::new(70.0, 0.0), 0));
+		dom.dispatch(&handle, &DomEvent::pointer("pointermove", Vector::new(0.0, 0.0), 0));
+		assert_eq!(dom.style(&side_bar.element, "width").as_deref(), Some("230px"));
+
+		dom.click(side_bar.x_button.element());
+		assert!(dom.children(&root).is_empty());
+		assert!(side_bar.content.borrow().is_none());
+		side_bar.destroy().unwrap();
+	}
 }
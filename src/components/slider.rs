@@ -1,70 +1,97 @@
 use std::{
-	ops::{Add, Sub},
+	ops::{Add, RangeInclusive, Sub},
 	rc::Rc,
 	str::FromStr,
 };
 
 use anyhow::Result;
 use result_or_err::ResultOrErr;
-use wasm_bindgen::JsCast;
-use web_sys::{HtmlDivElement, HtmlInputElement, InputEvent};
 
 use crate::{
-	elements::*,
-	events::{BubbleStopper, CustomEventListener, SharedEventListener},
+	dom::{Dom, DomListeners, WebDom},
+	errors::{ComponentErrorHandler, ElementError},
+	events::CustomEventListener,
 	Component, ComponentContent,
 };
 
 use super::Button;
 
-pub struct Slider<T> {
-	element: HtmlDivElement,
-	slider: HtmlInputElement,
-	buttons: Option<(Component<Button>, Component<Button>)>,
-	text: HtmlInputElement,
+/// The minus or the plus button.
+type StepButton<D> = Component<Button<D>, D>;
+
+pub struct Slider<T, D: Dom = WebDom> {
+	dom: Rc<D>,
+	element: D::Node,
+	slider: D::Node,
+	buttons: Option<(StepButton<D>, StepButton<D>)>,
+	text: D::Node,
 
 	pub on_change: CustomEventListener<T>,
 
-	slider_change: SharedEventListener<InputEvent>,
-	text_change: SharedEventListener<InputEvent>,
+	listeners: DomListeners<D>,
 }
 impl<T: FromStr + ToString + Clone + Add<T, Output = T> + Sub<T, Output = T> + 'static> Slider<T> {
+	/// Failures of the buttons go to the console (see [with_error_handler](Slider::with_error_handler)).
 	pub fn new(name: Option<&str>, value: T, min: T, max: T, step: T, buttons: bool, css: &str) -> Rc<Self> {
-		let element = styled(div(), css);
-		BubbleStopper::new(element.clone().into(), "click");
-		BubbleStopper::new(element.clone().into(), "pointerdown");
-		BubbleStopper::new(element.clone().into(), "pointermove");
-		BubbleStopper::new(element.clone().into(), "pointerup");
-		BubbleStopper::new(element.clone().into(), "contextmenu");
+		Self::new_in(WebDom::shared(), name, value, min..=max, step, buttons, css).unwrap()
+	}
+}
+impl<T: FromStr + ToString + Clone + Add<T, Output = T> + Sub<T, Output = T> + 'static, D: Dom> Slider<T, D> {
+	/// Like [new](Slider::new), but in the dom (and returns an error instead of panicking).
+	pub fn new_in(
+		dom: Rc<D>,
+		name: Option<&str>,
+		value: T,
+		range: RangeInclusive<T>,
+		step: T,
+		buttons: bool,
+		css: &str,
+	) -> Result<Rc<Self>, ElementError> {
+		let (min, max) = range.into_inner();
+		dom.register_default_style("Slider", super::styles::SLIDER);
+		let element = dom.create_styled("div", "slider")?;
+		dom.add_class(&element, css);
 
 		// label
 		if let Some(name) = name {
-			on(&element, label(name));
+			let label = dom.create_element("label")?;
+			dom.set_text(&label, name);
+			dom.append_child(&element, &label)?;
 		}
 		// slider
-		let slider = on(&element, slider(&value, &min, &max, &step));
-		let slider_change = SharedEventListener::<InputEvent>::new(slider.clone().into(), "input");
+		let slider = dom.create_input("range")?;
+		dom.set_attribute(&slider, "min", &min.to_string());
+		dom.set_attribute(&slider, "max", &max.to_string());
+		dom.set_attribute(&slider, "step", &step.to_string());
+		dom.set_value(&slider, &value.to_string());
+		dom.append_child(&element, &slider)?;
 		// text
-		let text = on(&element, text(&value.to_string()));
-		text.set_min(&min.to_string());
-		text.set_max(&max.to_string());
-		let text_change = SharedEventListener::<InputEvent>::new(text.clone().into(), "input");
+		let text = dom.create_input("text")?;
+		dom.set_attribute(&text, "min", &min.to_string());
+		dom.set_attribute(&text, "max", &max.to_string());
+		dom.set_value(&text, &value.to_string());
+		dom.append_child(&element, &text)?;
 		// buttons
 		let buttons = match buttons {
 			true => {
-				let div = on(&element, div());
+				let div = dom.create_element("div")?;
+				dom.append_child(&element, &div)?;
 
-				let minus = Component::make(Button::new(Some("-"), "settingsButton"));
-				minus.mount_in(&div).unwrap();
-				let plus = Component::make(Button::new(Some("+"), "settingsButton"));
-				plus.mount_in(&div).unwrap();
+				let minus = Component::make_in(dom.clone(), Button::new_in(dom.clone(), Some("-"), "settingsButton")?);
+				minus.mount_or_report(&div);
+				let plus = Component::make_in(dom.clone(), Button::new_in(dom.clone(), Some("+"), "settingsButton")?);
+				plus.mount_or_report(&div);
 
 				Some((minus, plus))
 			},
 			false => None,
 		};
 
+		let listeners = DomListeners::new(dom.clone());
+		listeners.stop_bubbling(&element, &super::CONTROL_EVENTS);
+
 		let this = Rc::new(Self {
+			dom,
 			element,
 			slider,
 			buttons,
@@ -72,39 +99,38 @@ impl<T: FromStr + ToString + Clone + Add<T, Output = T> + Sub<T, Output = T> + '
 
 			on_change: CustomEventListener::new(),
 
-			slider_change,
-			text_change,
+			listeners,
 		});
 
-		this.slider_change.set_handler({
+		this.listeners.add(&this.slider, "input", {
 			let this = this.clone();
-			move |event| {
-				let target = event.target().unwrap().dyn_into::<HtmlInputElement>().unwrap();
-				this.text.set_value(&target.value());
-				if let Ok(value) = target.value().parse::<T>() {
+			move |_| {
+				let value = this.dom.value(&this.slider).unwrap_or_default();
+				this.dom.set_value(&this.text, &value);
+				if let Ok(value) = value.parse::<T>() {
 					this.on_change.fire(value);
 				}
 			}
 		});
-		this.text_change.set_handler({
+		this.listeners.add(&this.text, "input", {
 			let this = this.clone();
-			move |event| {
-				let target = event.target().unwrap().dyn_into::<HtmlInputElement>().unwrap();
-				this.slider.set_value(&target.value());
-				if let Ok(value) = target.value().parse::<T>() {
+			move |_| {
+				let value = this.dom.value(&this.text).unwrap_or_default();
+				this.dom.set_value(&this.slider, &value);
+				if let Ok(value) = value.parse::<T>() {
 					this.on_change.fire(value);
 				}
 			}
 		});
 		if let Some((minus, plus)) = &this.buttons {
-			minus.on_click.set_handler({
+			_ = minus.on_click.set_handler({
 				let this = this.clone();
 				let step = step.clone();
 				move |_| {
 					this.change_value(this.get_value() - step.clone());
 				}
 			});
-			plus.on_click.set_handler({
+			_ = plus.on_click.set_handler({
 				let this = this.clone();
 				move |_| {
 					this.change_value(this.get_value() + step.clone());
@@ -112,19 +138,28 @@ impl<T: FromStr + ToString + Clone + Add<T, Output = T> + Sub<T, Output = T> + '
 			});
 		}
 
-		this
+		Ok(this)
+	}
+
+	/// Reports the failures of the buttons to the error handler (instead of the console).
+	pub fn with_error_handler(self: Rc<Self>, error_handler: impl ComponentErrorHandler) -> Rc<Self> {
+		if let Some((minus, plus)) = &self.buttons {
+			minus.set_error_handler(error_handler.clone_for("minus"));
+			plus.set_error_handler(error_handler.clone_for("plus"));
+		}
+		self
 	}
 
 	/// Set the value displayed by the ui component.
 	///
 	/// Does not trigger `on_change`!
 	pub fn set_value(&self, value: T) {
-		self.text.set_value(&value.to_string());
-		self.slider.set_value(&value.to_string());
+		self.dom.set_value(&self.text, &value.to_string());
+		self.dom.set_value(&self.slider, &value.to_string());
 	}
 
 	pub fn get_value(&self) -> T {
-		self.text.value().parse::<T>().or_err(()).unwrap()
+		self.dom.value(&self.text).unwrap_or_default().parse::<T>().or_err(()).unwrap()
 	}
 
 	/// Set the value displayed by the ui component and trigger `on_change`.
@@ -135,12 +170,70 @@ impl<T: FromStr + ToString + Clone + Add<T, Output = T> + Sub<T, Output = T> + '
 		self.on_change.fire(value);
 	}
 }
-impl<T: FromStr + ToString> ComponentContent for Slider<T> {
-	fn element(&self) -> &web_sys::Element {
+impl<T: FromStr + ToString, D: Dom> ComponentContent<D> for Slider<T, D> {
+	fn element(&self) -> &D::Node {
 		&self.element
 	}
 	fn destroy(&self) -> Result<()> {
-		self.slider_change.remove_handler();
+		self.listeners.clear();
+		if let Some((minus, plus)) = &self.buttons {
+			minus.on_click.remove_handler();
+			plus.on_click.remove_handler();
+		}
 		Ok(())
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use std::cell::RefCell;
+
+	use super::*;
+	use crate::{
+		dom::MemoryDom,
+		errors::{LifecycleStage, StringErrorHandler},
+	};
+
+	#[test]
+	fn follows_drags_typing_and_buttons() {
+		let dom = MemoryDom::new();
+		let (handler, errors) = StringErrorHandler::new("Slider".to_owned());
+		let slider = Slider::new_in(dom.clone(), Some("Size"), 5, 0..=10, 2, true, "setting").unwrap();
+		let slider = slider.with_error_handler(handler);
+		let changes = Rc::new(RefCell::new(Vec::new()));
+		_ = slider.on_change.set_handler({
+			let changes = changes.clone();
+			move |value| changes.borrow_mut().push(value)
+		});
+		let inputs: Vec<_> =
+			dom.children(slider.element()).into_iter().filter(|child| dom.tag(child) == "input").collect();
+		let (range, text) = (inputs[0].clone(), inputs[1].clone());
+		let buttons = dom.find_by_class(slider.element(), "settingsButton");
+
+		// dragging the range input
+		dom.input(&range, "7");
+		assert_eq!(dom.value(&text).as_deref(), Some("7"));
+		// typing (only numbers are reported)
+		dom.input(&text, "3");
+		dom.input(&text, "3.");
+		assert_eq!(dom.value(&range).as_deref(), Some("3."));
+		dom.input(&text, "4");
+		// minus and plus
+		dom.click(&buttons[0]);
+		dom.click(&buttons[1]);
+		dom.click(&buttons[1]);
+		assert_eq!(*changes.borrow(), vec![7, 3, 4, 2, 4, 6]);
+		assert_eq!(slider.get_value(), 6);
+		assert_eq!(dom.value(&range).as_deref(), Some("6"));
+
+		slider.set_value(1);
+		assert_eq!(changes.borrow().len(), 6);
+		assert_eq!(dom.value(&range).as_deref(), Some("1"));
+
+		// failures of the buttons go to the handler
+		let (minus, _) = slider.buttons.as_ref().unwrap();
+		minus.report(LifecycleStage::Update, Err(anyhow::anyhow!("broken")));
+		assert_eq!(errors.get().len(), 1);
+		assert!(errors.get()[0].contains("minus"));
+	}
+}
@@ -0,0 +1,72 @@
+//! The default styles of the built-in components.
+//!
+//! They are registered with [StyleSheets::register_default] (keyed by the component's type name) when a component is created.
+//! See [StyleSheets] for how to opt out or override them.
+//!
+//! The styles read the variables of the current [Theme](crate::elements::Theme) and fall back to the light theme without one.
+
+use crate::elements::StyleSheets;
+
+pub const BUTTON: &str = "\
+.button { padding: var(--webbit-spacing-small, 4px) var(--webbit-spacing-medium, 8px); \
+border-radius: var(--webbit-radius-small, 4px); color: var(--webbit-color-text, #18181b); \
+font-family: var(--webbit-font-body, system-ui, sans-serif); cursor: pointer; user-select: none; }
+.button:hover { background: var(--webbit-color-hover, #f4f4f5); }
+";
+
+pub const SELECTION_FRAME: &str = "\
+.selection-frame { position: fixed; box-sizing: border-box; }
+.selection-frame .selection-canvas { position: absolute; inset: 0; width: 100%; height: 100%; }
+.selection-frame .selection-outline { position: absolute; inset: 0; }
+.selection-frame .selection-border { position: absolute; inset: -1px; \
+border: 1px dashed var(--webbit-color-primary, #3b82f6); pointer-events: none; }
+.selection-frame .resize-button { position: absolute; right: -6px; bottom: -6px; width: 12px; height: 12px; \
+box-sizing: border-box; border: 1px solid var(--webbit-color-primary, #3b82f6); \
+background: var(--webbit-color-background, white); cursor: nwse-resize; touch-action: none; }
+.selection-frame .control-knob { position: absolute; left: 50%; top: -28px; width: 20px; height: 20px; \
+transform: translateX(-50%); }
+.selection-frame .move-button { width: 100%; height: 100%; box-sizing: border-box; \
+border: 1px solid var(--webbit-color-primary, #3b82f6); border-radius: 50%; \
+background: var(--webbit-color-background, white); cursor: move; touch-action: none; }
+";
+
+pub const CONTEXT_MENU: &str = "\
+.context-menu { display: flex; flex-direction: column; min-width: 160px; \
+padding: var(--webbit-spacing-small, 4px); gap: var(--webbit-spacing-small, 4px); \
+color: var(--webbit-color-text, #18181b); background: var(--webbit-color-background, white); \
+border: 1px solid var(--webbit-color-border, #d4d4d8); border-radius: var(--webbit-radius-medium, 6px); \
+box-shadow: 0 4px 12px var(--webbit-color-shadow, rgba(0, 0, 0, 0.15)); \
+font-family: var(--webbit-font-body, system-ui, sans-serif); }
+.context-menu .context-menu-quick-section { display: flex; gap: var(--webbit-spacing-small, 4px); }
+.context-menu .context-menu-section { display: flex; flex-direction: column; }
+";
+
+pub const SIDE_BAR: &str = "\
+.side-bar { position: fixed; top: 0; right: 0; bottom: 0; display: flex; width: 300px; min-width: 120px; \
+color: var(--webbit-color-text, #18181b); background: var(--webbit-color-surface, #fafafa); \
+border-left: 1px solid var(--webbit-color-border, #d4d4d8); font-family: var(--webbit-font-body, system-ui, sans-serif); }
+.side-bar .side-bar-handle { width: var(--webbit-spacing-small, 4px); cursor: ew-resize; touch-action: none; }
+.side-bar .side-bar-internal { display: flex; flex: 1; flex-direction: column; min-width: 0; }
+.side-bar .side-bar-controls { display: flex; align-items: center; gap: var(--webbit-spacing-medium, 8px); \
+padding: var(--webbit-spacing-small, 4px) var(--webbit-spacing-medium, 8px); \
+border-bottom: 1px solid var(--webbit-color-border, #d4d4d8); }
+.side-bar .side-bar-content { flex: 1; overflow: auto; padding: var(--webbit-spacing-medium, 8px); }
+";
+
+pub const SLIDER: &str = "\
+.slider { display: flex; align-items: center; gap: var(--webbit-spacing-medium, 8px); \
+color: var(--webbit-color-text, #18181b); font-family: var(--webbit-font-body, system-ui, sans-serif); }
+.slider input[type=range] { flex: 1; min-width: 0; accent-color: var(--webbit-color-primary, #3b82f6); }
+.slider input[type=text], .slider input[type=number] { width: 4em; color: inherit; \
+background: var(--webbit-color-background, white); border: 1px solid var(--webbit-color-border, #d4d4d8); \
+border-radius: var(--webbit-radius-small, 4px); font-family: var(--webbit-font-mono, ui-monospace, monospace); }
+";
+
+/// Registers the default styles of all built-in components at once (e.g. before the first one is created).
+pub fn register_all() {
+	_ = StyleSheets::register_default("Button", BUTTON);
+	_ = StyleSheets::register_default("SelectionFrame", SELECTION_FRAME);
+	_ = StyleSheets::register_default("ContextMenu", CONTEXT_MENU);
+	_ = StyleSheets::register_default("SideBar", SIDE_BAR);
+	_ = StyleSheets::register_default("Slider", SLIDER);
+}
@@ -3,7 +3,10 @@ use std::{
 	rc::Rc,
 };
 
-use crate::errors::{ContextInUseError, Environment, ErrorHandler, TracksEnvironment};
+use crate::{
+	errors::{ContextInUseError, Environment, ErrorHandler, TracksEnvironment},
+	Lens,
+};
 
 /// To communicate with stuff that our component is not responsible for we probably want to use an Rc<RefCell<>>.
 /// Calling it `Reference` looks nicer.
@@ -47,6 +50,20 @@ impl<T, H: ErrorHandler<ContextInUseError>> Context<T, H> {
 	pub fn access_mut_or<'b, Err>(&'b self, err: Err) -> Result<RefMut<'b, T>, Err> {
 		self.access_mut().ok_or(err)
 	}
+
+	/// Reads the field (like [Context::access], failures are reported to the error handler).
+	pub fn read<F: Clone>(&self, lens: &Lens<T, F>) -> Option<F> {
+		self.access().map(|data| lens.get(&data).clone())
+	}
+	/// Writes the field (like [Context::access_mut], failures are reported to the error handler).
+	///
+	/// # Returns
+	/// Whether the field was written.
+	pub fn write<F>(&self, lens: &Lens<T, F>, value: F) -> bool {
+		let Some(mut data) = self.access_mut() else { return false };
+		*lens.get_mut(&mut data) = value;
+		true
+	}
 }
 impl<T, H, E> ErrorHandler<E> for Context<T, H>
 where
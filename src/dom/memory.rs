@@ -0,0 +1,460 @@
+use std::{
+	cell::{Cell, RefCell},
+	collections::HashMap,
+	fmt::Debug,
+	hash::Hash,
+	rc::{Rc, Weak},
+};
+
+use super::{Dom, DomEvent, DomNode, ListenerId};
+use crate::{
+	common::Vector,
+	errors::{ElementError, StyleError},
+};
+
+/// An element of a [MemoryDom].
+///
+/// It knows its dom (so it can be mounted without it, see [DomNode]).
+#[derive(Clone)]
+pub struct MemoryNode {
+	id: usize,
+	dom: Weak<MemoryDom>,
+}
+impl PartialEq for MemoryNode {
+	fn eq(&self, other: &Self) -> bool {
+		self.id == other.id && self.dom.ptr_eq(&other.dom)
+	}
+}
+impl Eq for MemoryNode {}
+impl Hash for MemoryNode {
+	fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+		self.id.hash(state);
+	}
+}
+impl Debug for MemoryNode {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_tuple("MemoryNode").field(&self.id).finish()
+	}
+}
+impl DomNode for MemoryNode {
+	fn append(&self, child: &Self) -> Result<(), ElementError> {
+		let dom = self.dom.upgrade().ok_or(ElementError::InsertFailed)?;
+		dom.append_child(self, child)
+	}
+	fn detach(&self) {
+		let Some(dom) = self.dom.upgrade() else { return };
+		dom.remove(self);
+	}
+}
+
+#[derive(Default)]
+struct NodeData {
+	/// `None` for text
+	tag: Option<String>,
+	text: String,
+	attributes: Vec<(String, String)>,
+	classes: Vec<String>,
+	styles: Vec<(String, String)>,
+	parent: Option<usize>,
+	children: Vec<usize>,
+}
+
+type Handler = Rc<RefCell<Box<dyn FnMut(&DomEvent)>>>;
+
+struct Listener {
+	node: usize,
+	kind: String,
+	handler: Handler,
+}
+
+/// A DOM that only exists in memory (so it works without a browser, e.g. in native tests).
+///
+/// Nodes are never freed (it's meant for short-lived documents).
+/// Events bubble up to the ancestors of their node, like in the browser.
+/// There is no layout: the [size](Dom::size) of an element is its inline `width` and `height` (in px).
+///
+/// # Example
+/// ```text
+/// let dom = MemoryDom::new();
+/// let button = dom.create_element("div")?;
+/// dom.add_listener(&button, "click", Box::new(|_| clicked.set(true)));
+/// dom.click(&button);
+/// ```
+pub struct MemoryDom {
+	/// for the nodes (they know their dom)
+	this: Weak<MemoryDom>,
+	nodes: RefCell<Vec<NodeData>>,
+	listeners: RefCell<HashMap<usize, Listener>>,
+	last_listener: Cell<usize>,
+}
+
+impl MemoryDom {
+	pub fn new() -> Rc<Self> {
+		Rc::new_cyclic(|this| Self {
+			this: this.clone(),
+			nodes: RefCell::new(Vec::new()),
+			listeners: RefCell::new(HashMap::new()),
+			last_listener: Cell::new(0),
+		})
+	}
+
+	/// Dispatches a click of the primary button at the origin.
+	pub fn click(&self, node: &MemoryNode) -> bool {
+		self.dispatch(node, &DomEvent::pointer("click", Vector::zero(), 0))
+	}
+	/// Dispatches `pointerdown`, `pointerup` and `click` with the primary button at the position.
+	pub fn tap(&self, node: &MemoryNode, position: Vector) {
+		self.dispatch(node, &DomEvent::pointer("pointerdown", position, 1));
+		self.dispatch(node, &DomEvent::pointer("pointerup", position, 0));
+		self.dispatch(node, &DomEvent::pointer("click", position, 0));
+	}
+	/// Enters the value into the input (like the user would) and dispatches `input`.
+	pub fn input(&self, node: &MemoryNode, value: &str) -> bool {
+		self.set_value(node, value);
+		self.dispatch(node, &DomEvent::input(value))
+	}
+	/// Toggles the checkbox (like the user would) and dispatches `change`.
+	pub fn toggle(&self, node: &MemoryNode) -> bool {
+		let checked = !self.checked(node).unwrap_or(false);
+		self.set_checked(node, checked);
+		self.dispatch(node, &DomEvent::change(checked))
+	}
+
+	/// The elements with the class (the node itself and everything below it, in document order).
+	pub fn find_by_class(&self, node: &MemoryNode, class: &str) -> Vec<MemoryNode> {
+		let mut found = Vec::new();
+		if self.has_class(node, class) {
+			found.push(node.clone());
+		}
+		for child in self.children(node) {
+			found.extend(self.find_by_class(&child, class));
+		}
+		found
+	}
+
+	fn node(&self, id: usize) -> MemoryNode {
+		MemoryNode { id, dom: self.this.clone() }
+	}
+	fn with<R>(&self, node: &MemoryNode, f: impl FnOnce(&NodeData) -> R) -> R {
+		f(&self.nodes.borrow()[node.id])
+	}
+	fn with_mut<R>(&self, node: &MemoryNode, f: impl FnOnce(&mut NodeData) -> R) -> R {
+		f(&mut self.nodes.borrow_mut()[node.id])
+	}
+	fn push(&self, data: NodeData) -> MemoryNode {
+		let mut nodes = self.nodes.borrow_mut();
+		nodes.push(data);
+		self.node(nodes.len() - 1)
+	}
+	fn create_text(&self, text: &str) -> MemoryNode {
+		self.push(NodeData { text: text.to_owned(), ..Default::default() })
+	}
+	fn is_text(&self, node: &MemoryNode) -> bool {
+		self.with(node, |data| data.tag.is_none())
+	}
+	/// The children including text.
+	fn child_nodes(&self, node: &MemoryNode) -> Vec<MemoryNode> {
+		let children = self.with(node, |data| data.children.clone());
+		children.into_iter().map(|id| self.node(id)).collect()
+	}
+	/// The inline style property in px (0 if it isn't set).
+	fn pixels(&self, node: &MemoryNode, property: &str) -> f64 {
+		let value = self.style(node, property).unwrap_or_default();
+		value.trim_end_matches("px").trim().parse().unwrap_or(0.0)
+	}
+	fn is_ancestor(&self, ancestor: &MemoryNode, node: &MemoryNode) -> bool {
+		let mut current = Some(node.clone());
+		while let Some(node) = current {
+			if node == *ancestor {
+				return true;
+			}
+			current = self.parent(&node);
+		}
+		false
+	}
+}
+
+fn set(entries: &mut Vec<(String, String)>, name: &str, value: &str) {
+	match entries.iter_mut().find(|(existing, _)| existing == name) {
+		Some((_, existing)) => *existing = value.to_owned(),
+		None => entries.push((name.to_owned(), value.to_owned())),
+	}
+}
+fn get(entries: &[(String, String)], name: &str) -> Option<String> {
+	entries.iter().find(|(existing, _)| existing == name).map(|(_, value)| value.clone())
+}
+
+impl Dom for MemoryDom {
+	type Node = MemoryNode;
+	type PointerEvent = DomEvent;
+
+	fn create_element(&self, tag: &str) -> Result<MemoryNode, ElementError> {
+		if tag.is_empty() || !tag.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+			return Err(ElementError::CreateFailed(tag.to_owned()));
+		}
+		Ok(self.push(NodeData { tag: Some(tag.to_ascii_lowercase()), ..Default::default() }))
+	}
+	fn tag(&self, node: &MemoryNode) -> String {
+		self.with(node, |data| data.tag.clone().unwrap_or_default())
+	}
+
+	fn attribute(&self, node: &MemoryNode, name: &str) -> Option<String> {
+		if name == "class" {
+			return self.with(node, |data| (!data.classes.is_empty()).then(|| data.classes.join(" ")));
+		}
+		self.with(node, |data| get(&data.attributes, name))
+	}
+	fn set_attribute(&self, node: &MemoryNode, name: &str, value: &str) {
+		match name {
+			"class" => self.with_mut(node, |data| {
+				data.classes = value.split_whitespace().map(str::to_owned).collect();
+			}),
+			_ => self.with_mut(node, |data| set(&mut data.attributes, name, value)),
+		}
+	}
+	fn remove_attribute(&self, node: &MemoryNode, name: &str) {
+		match name {
+			"class" => self.with_mut(node, |data| data.classes.clear()),
+			_ => self.with_mut(node, |data| data.attributes.retain(|(existing, _)| existing != name)),
+		}
+	}
+
+	fn add_class(&self, node: &MemoryNode, class: &str) {
+		self.with_mut(node, |data| {
+			for class in class.split_whitespace() {
+				if !data.classes.iter().any(|existing| existing == class) {
+					data.classes.push(class.to_owned());
+				}
+			}
+		});
+	}
+	fn remove_class(&self, node: &MemoryNode, class: &str) {
+		let removed: Vec<&str> = class.split_whitespace().collect();
+		self.with_mut(node, |data| data.classes.retain(|existing| !removed.contains(&existing.as_str())));
+	}
+	fn has_class(&self, node: &MemoryNode, class: &str) -> bool {
+		self.with(node, |data| data.classes.iter().any(|existing| existing == class))
+	}
+
+	fn style(&self, node: &MemoryNode, property: &str) -> Option<String> {
+		self.with(node, |data| get(&data.styles, property))
+	}
+	fn set_style(&self, node: &MemoryNode, property: &str, value: &str) -> Result<(), StyleError> {
+		if self.is_text(node) {
+			return Err(StyleError { property: property.to_owned(), value: value.to_owned() });
+		}
+		self.with_mut(node, |data| match value.is_empty() {
+			true => data.styles.retain(|(existing, _)| existing != property),
+			false => set(&mut data.styles, property, value),
+		});
+		Ok(())
+	}
+	fn size(&self, node: &MemoryNode) -> Vector {
+		Vector::new(self.pixels(node, "width"), self.pixels(node, "height"))
+	}
+
+	fn text(&self, node: &MemoryNode) -> String {
+		if self.is_text(node) {
+			return self.with(node, |data| data.text.clone());
+		}
+		self.child_nodes(node).iter().map(|child| self.text(child)).collect()
+	}
+	fn set_text(&self, node: &MemoryNode, text: &str) {
+		if self.is_text(node) {
+			return self.with_mut(node, |data| data.text = text.to_owned());
+		}
+		for child in self.child_nodes(node) {
+			self.remove(&child);
+		}
+		if !text.is_empty() {
+			let text = self.create_text(text);
+			_ = self.append_child(node, &text);
+		}
+	}
+	fn value(&self, node: &MemoryNode) -> Option<String> {
+		match self.tag(node).as_str() {
+			"input" | "textarea" | "select" => Some(self.attribute(node, "value").unwrap_or_default()),
+			_ => None,
+		}
+	}
+	fn set_value(&self, node: &MemoryNode, value: &str) {
+		self.set_attribute(node, "value", value);
+	}
+	fn checked(&self, node: &MemoryNode) -> Option<bool> {
+		match self.tag(node).as_str() {
+			"input" => Some(self.attribute(node, "checked").is_some()),
+			_ => None,
+		}
+	}
+	fn set_checked(&self, node: &MemoryNode, checked: bool) {
+		match checked {
+			true => self.set_attribute(node, "checked", ""),
+			false => self.remove_attribute(node, "checked"),
+		}
+	}
+
+	fn append_child(&self, parent: &MemoryNode, child: &MemoryNode) -> Result<(), ElementError> {
+		self.insert_before(parent, child, None)
+	}
+	fn insert_before(
+		&self,
+		parent: &MemoryNode,
+		child: &MemoryNode,
+		reference: Option<&MemoryNode>,
+	) -> Result<(), ElementError> {
+		// like in the browser: no cycles and the reference must be a child of the parent
+		if self.is_text(parent) || self.is_ancestor(child, parent) {
+			return Err(ElementError::InsertFailed);
+		}
+		let foreign = |reference: &MemoryNode| self.parent(reference).as_ref() != Some(parent);
+		if reference.is_some_and(|reference| foreign(reference) || reference == child) {
+			return Err(ElementError::InsertFailed);
+		}
+		self.remove(child);
+		self.with_mut(child, |data| data.parent = Some(parent.id));
+		self.with_mut(parent, |data| {
+			let index = reference
+				.and_then(|reference| data.children.iter().position(|existing| *existing == reference.id))
+				.unwrap_or(data.children.len());
+			data.children.insert(index, child.id);
+		});
+		Ok(())
+	}
+	fn remove(&self, node: &MemoryNode) {
+		let Some(parent) = self.with_mut(node, |data| data.parent.take()) else { return };
+		self.with_mut(&self.node(parent), |data| data.children.retain(|existing| *existing != node.id));
+	}
+	fn parent(&self, node: &MemoryNode) -> Option<MemoryNode> {
+		self.with(node, |data| data.parent).map(|id| self.node(id))
+	}
+	fn children(&self, node: &MemoryNode) -> Vec<MemoryNode> {
+		self.child_nodes(node).into_iter().filter(|child| !self.is_text(child)).collect()
+	}
+
+	fn add_listener(&self, node: &MemoryNode, kind: &str, handler: Box<dyn FnMut(&DomEvent)>) -> ListenerId {
+		let id = self.last_listener.get() + 1;
+		self.last_listener.set(id);
+		let listener = Listener { node: node.id, kind: kind.to_owned(), handler: Rc::new(RefCell::new(handler)) };
+		self.listeners.borrow_mut().insert(id, listener);
+		ListenerId(id)
+	}
+	fn remove_listener(&self, id: ListenerId) {
+		self.listeners.borrow_mut().remove(&id.0);
+	}
+	fn dispatch(&self, node: &MemoryNode, event: &DomEvent) -> bool {
+		let mut current = Some(node.clone());
+		while let Some(node) = current {
+			// handlers may add or remove listeners, so they are called without borrowing them
+			let mut handlers: Vec<(usize, Handler)> = self
+				.listeners
+				.borrow()
+				.iter()
+				.filter(|(_, listener)| listener.node == node.id && listener.kind == event.kind)
+				.map(|(id, listener)| (*id, listener.handler.clone()))
+				.collect();
+			// in the order they were added
+			handlers.sort_by_key(|(id, _)| *id);
+			for (_, handler) in handlers {
+				let Ok(mut handler) = handler.try_borrow_mut() else { continue };
+				handler(event);
+			}
+			if event.propagation_stopped() {
+				break;
+			}
+			current = self.parent(&node);
+		}
+		!event.default_prevented()
+	}
+	fn pointer_event(event: &DomEvent) -> Option<DomEvent> {
+		Some(event.clone())
+	}
+	/// Does nothing (events are only dispatched explicitly).
+	fn capture_pointer(&self, _node: &MemoryNode, _pointer_id: i32) {}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn builds_trees() {
+		let dom = MemoryDom::new();
+		let list = dom.create_element("ul").unwrap();
+		let items: Vec<MemoryNode> = (0..3).map(|_| dom.create_element("li").unwrap()).collect();
+		for (index, item) in items.iter().enumerate() {
+			dom.set_text(item, &index.to_string());
+			dom.append_child(&list, item).unwrap();
+		}
+		dom.insert_before(&list, &items[2], Some(&items[0])).unwrap();
+		assert_eq!(dom.text(&list), "201");
+		dom.remove(&items[0]);
+		assert_eq!(dom.children(&list), vec![items[2].clone(), items[1].clone()]);
+		assert!(dom.children(&items[1]).is_empty());
+		assert!(dom.append_child(&items[1], &list).is_err());
+
+		dom.set_attribute(&list, "class", "menu open");
+		dom.remove_class(&list, "open");
+		dom.add_class(&list, "wide");
+		assert_eq!(dom.attribute(&list, "class").as_deref(), Some("menu wide"));
+		dom.set_style(&list, "width", "20px").unwrap();
+		assert_eq!(dom.style(&list, "width").as_deref(), Some("20px"));
+		assert_eq!(dom.size(&list), Vector::new(20.0, 0.0));
+		assert!(dom.create_element("not a tag").is_err());
+	}
+
+	#[test]
+	fn bubbles_events() {
+		let dom = MemoryDom::new();
+		let outer = dom.create_element("div").unwrap();
+		let inner = dom.create_element("div").unwrap();
+		dom.append_child(&outer, &inner).unwrap();
+
+		let seen = Rc::new(RefCell::new(Vec::new()));
+		let record = |name: &'static str, stop: bool| {
+			let seen = seen.clone();
+			Box::new(move |event: &DomEvent| {
+				seen.borrow_mut().push((name, event.position));
+				if stop {
+					event.stop_propagation();
+					event.prevent_default();
+				}
+			})
+		};
+		dom.add_listener(&outer, "pointerdown", record("outer", false));
+		let listener = dom.add_listener(&inner, "pointerdown", record("inner", false));
+
+		dom.tap(&inner, Vector::new(1.0, 2.0));
+		let position = Some(Vector::new(1.0, 2.0));
+		assert_eq!(*seen.borrow(), vec![("inner", position), ("outer", position)]);
+
+		seen.borrow_mut().clear();
+		dom.remove_listener(listener);
+		dom.add_listener(&inner, "pointerdown", record("stopper", true));
+		assert!(!dom.dispatch(&inner, &DomEvent::pointer("pointerdown", Vector::zero(), 1)));
+		assert_eq!(*seen.borrow(), vec![("stopper", Some(Vector::zero()))]);
+	}
+
+	#[test]
+	fn drives_inputs() {
+		let dom = MemoryDom::new();
+		let text = dom.create_element("input").unwrap();
+		let checkbox = dom.create_element("input").unwrap();
+		let values = Rc::new(RefCell::new(Vec::new()));
+		dom.add_listener(&text, "input", {
+			let values = values.clone();
+			Box::new(move |event: &DomEvent| values.borrow_mut().push(event.value.clone()))
+		});
+		dom.add_listener(&checkbox, "change", {
+			let values = values.clone();
+			Box::new(move |event: &DomEvent| {
+				values.borrow_mut().push(event.checked.map(|checked| checked.to_string()));
+			})
+		});
+
+		dom.input(&text, "42");
+		dom.toggle(&checkbox);
+		assert_eq!(dom.value(&text).as_deref(), Some("42"));
+		assert_eq!(dom.checked(&checkbox), Some(true));
+		assert_eq!(*values.borrow(), vec![Some("42".to_owned()), Some("true".to_owned())]);
+	}
+}
@@ -0,0 +1,208 @@
+//! An abstraction over the DOM, so code that builds and drives ui can run without a browser.
+//!
+//! - [Dom]: element creation, attributes, classes, styles, tree operations and event dispatch
+//! - [WebDom]: the browser's DOM (through `web_sys`)
+//! - [MemoryDom]: an in-memory DOM (e.g. to drive ui with synthetic events in native `cargo test`s)
+//! - [DomListeners]: listeners that are removed together (e.g. when their component is destroyed)
+
+use std::{
+	cell::{Cell, RefCell},
+	rc::Rc,
+};
+
+use crate::{
+	common::Vector,
+	errors::{ElementError, StyleError},
+};
+
+mod web;
+pub use web::*;
+
+mod memory;
+pub use memory::*;
+
+/// The tree operations a node can do on its own (it knows its document).
+///
+/// These let [ComponentContent](crate::ComponentContent) mount and unmount without the dom.
+pub trait DomNode: Clone + PartialEq + 'static {
+	/// Appends the child (moving it if it already has a parent).
+	fn append(&self, child: &Self) -> Result<(), ElementError>;
+	/// Removes the node from its parent (if it has one).
+	fn detach(&self);
+}
+
+/// The operations on a document that components need.
+///
+/// Nodes are elements and they are handles (cloning one doesn't clone the element).
+/// Text is only accessed through the element that contains it (see [text](Dom::text)).
+pub trait Dom: 'static {
+	type Node: DomNode;
+	/// What pointer handlers get (e.g. the `on_click` of [Button](crate::components::Button)).
+	type PointerEvent: Clone + 'static;
+
+	fn create_element(&self, tag: &str) -> Result<Self::Node, ElementError>;
+	/// Creates an element with the css classes (separated by whitespace).
+	fn create_styled(&self, tag: &str, css: &str) -> Result<Self::Node, ElementError> {
+		let element = self.create_element(tag)?;
+		self.add_class(&element, css);
+		Ok(element)
+	}
+	/// Creates an input of the type (e.g. `"checkbox"`).
+	fn create_input(&self, kind: &str) -> Result<Self::Node, ElementError> {
+		let input = self.create_element("input")?;
+		self.set_attribute(&input, "type", kind);
+		Ok(input)
+	}
+	/// The (lowercase) tag of the element.
+	fn tag(&self, node: &Self::Node) -> String;
+
+	fn attribute(&self, node: &Self::Node, name: &str) -> Option<String>;
+	fn set_attribute(&self, node: &Self::Node, name: &str, value: &str);
+	fn remove_attribute(&self, node: &Self::Node, name: &str);
+
+	fn add_class(&self, node: &Self::Node, class: &str);
+	fn remove_class(&self, node: &Self::Node, class: &str);
+	fn has_class(&self, node: &Self::Node, class: &str) -> bool;
+
+	/// The inline style property (`None` if it isn't set).
+	fn style(&self, node: &Self::Node, property: &str) -> Option<String>;
+	fn set_style(&self, node: &Self::Node, property: &str, value: &str) -> Result<(), StyleError>;
+	/// The size the element is rendered with (its client width and height).
+	fn size(&self, node: &Self::Node) -> Vector;
+	/// Registers the default styles of a component (see [StyleSheets](crate::elements::StyleSheets)).
+	///
+	/// Does nothing by default (for documents without stylesheets).
+	fn register_default_style(&self, _key: &str, _css: &str) {}
+
+	/// The text of the element and all elements below it.
+	fn text(&self, node: &Self::Node) -> String;
+	/// Replaces the children of the node with the text.
+	fn set_text(&self, node: &Self::Node, text: &str);
+	/// The value of an input (`None` if the node isn't one).
+	fn value(&self, node: &Self::Node) -> Option<String>;
+	fn set_value(&self, node: &Self::Node, value: &str);
+	/// Whether a checkbox is checked (`None` if the node isn't an input).
+	fn checked(&self, node: &Self::Node) -> Option<bool>;
+	fn set_checked(&self, node: &Self::Node, checked: bool);
+
+	/// Appends the child (moving it if it already has a parent).
+	fn append_child(&self, parent: &Self::Node, child: &Self::Node) -> Result<(), ElementError>;
+	/// Inserts the child before the reference (or at the end without one).
+	fn insert_before(
+		&self,
+		parent: &Self::Node,
+		child: &Self::Node,
+		reference: Option<&Self::Node>,
+	) -> Result<(), ElementError>;
+	/// Removes the node from its parent.
+	fn remove(&self, node: &Self::Node);
+	fn parent(&self, node: &Self::Node) -> Option<Self::Node>;
+	/// The child elements (without text).
+	fn children(&self, node: &Self::Node) -> Vec<Self::Node>;
+
+	/// Calls the handler for events of the kind (e.g. `"pointerdown"`) on the node or below it.
+	fn add_listener(&self, node: &Self::Node, kind: &str, handler: Box<dyn FnMut(&DomEvent)>) -> ListenerId;
+	fn remove_listener(&self, id: ListenerId);
+	/// Dispatches the event on the node.
+	///
+	/// # Returns
+	/// Whether the default action should happen (no handler [prevented](DomEvent::prevent_default) it).
+	fn dispatch(&self, node: &Self::Node, event: &DomEvent) -> bool;
+	/// The pointer event of the event (`None` if it isn't one).
+	fn pointer_event(event: &DomEvent) -> Option<Self::PointerEvent>;
+	/// Sends the following events of the pointer to the node (until it is released).
+	fn capture_pointer(&self, node: &Self::Node, pointer_id: i32);
+}
+
+/// Listeners added to a [Dom] that are removed when this is dropped (or [cleared](DomListeners::clear)).
+///
+/// Handlers that hold an Rc to their component have to be cleared when the component is destroyed (they'd keep it alive otherwise).
+pub struct DomListeners<D: Dom> {
+	dom: Rc<D>,
+	ids: RefCell<Vec<ListenerId>>,
+}
+
+impl<D: Dom> DomListeners<D> {
+	pub fn new(dom: Rc<D>) -> Self {
+		Self { dom, ids: RefCell::new(Vec::new()) }
+	}
+
+	/// Adds the listener (see [Dom::add_listener]).
+	pub fn add(&self, node: &D::Node, kind: &str, handler: impl FnMut(&DomEvent) + 'static) {
+		let id = self.dom.add_listener(node, kind, Box::new(handler));
+		self.ids.borrow_mut().push(id);
+	}
+	/// Stops the events of the kinds from bubbling up from the node (like [BubbleStopper](crate::events::BubbleStopper)).
+	pub fn stop_bubbling(&self, node: &D::Node, kinds: &[&str]) {
+		for kind in kinds {
+			self.add(node, kind, |event| event.stop_propagation());
+		}
+	}
+	/// Removes all listeners.
+	pub fn clear(&self) {
+		let ids = std::mem::take(&mut *self.ids.borrow_mut());
+		for id in ids {
+			self.dom.remove_listener(id);
+		}
+	}
+}
+
+impl<D: Dom> Drop for DomListeners<D> {
+	fn drop(&mut self) {
+		self.clear();
+	}
+}
+
+/// A listener added with [Dom::add_listener].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ListenerId(pub(crate) usize);
+
+/// An event with the data of the kinds components handle (pointer and input events).
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct DomEvent {
+	pub kind: String,
+	/// the pointer position (in client coordinates)
+	pub position: Option<Vector>,
+	/// the pressed buttons (as in `MouseEvent.buttons`)
+	pub buttons: u16,
+	pub pointer_id: Option<i32>,
+	/// the value of the input the event happened on
+	pub value: Option<String>,
+	/// whether the checkbox the event happened on is checked
+	pub checked: Option<bool>,
+	/// the browser's event (for events of a [WebDom])
+	pub native: Option<web_sys::Event>,
+	propagation_stopped: Cell<bool>,
+	default_prevented: Cell<bool>,
+}
+
+impl DomEvent {
+	pub fn new(kind: &str) -> Self {
+		Self { kind: kind.to_owned(), ..Default::default() }
+	}
+	/// A pointer event (e.g. `"pointerdown"`) of the primary pointer.
+	pub fn pointer(kind: &str, position: Vector, buttons: u16) -> Self {
+		Self { position: Some(position), buttons, pointer_id: Some(1), ..Self::new(kind) }
+	}
+	/// An `input` event of an input with the value.
+	pub fn input(value: &str) -> Self {
+		Self { value: Some(value.to_owned()), ..Self::new("input") }
+	}
+	/// A `change` event of a checkbox.
+	pub fn change(checked: bool) -> Self {
+		Self { checked: Some(checked), ..Self::new("change") }
+	}
+
+	pub fn stop_propagation(&self) {
+		self.propagation_stopped.set(true);
+	}
+	pub fn propagation_stopped(&self) -> bool {
+		self.propagation_stopped.get()
+	}
+	pub fn prevent_default(&self) {
+		self.default_prevented.set(true);
+	}
+	pub fn default_prevented(&self) -> bool {
+		self.default_prevented.get()
+	}
+}
@@ -0,0 +1,261 @@
+use std::{
+	cell::{Cell, RefCell},
+	collections::HashMap,
+	rc::Rc,
+};
+
+use wasm_bindgen::{prelude::Closure, JsCast};
+use web_sys::{
+	Document, Element, Event, EventInit, HtmlElement, HtmlInputElement, MouseEvent, PointerEvent,
+	PointerEventInit,
+};
+
+use super::{Dom, DomEvent, DomNode, ListenerId};
+use crate::{
+	common::Vector,
+	elements::{document, try_document, StyleSheets},
+	errors::{ElementError, StyleError},
+};
+
+thread_local! {
+	static SHARED: Rc<WebDom> = Rc::new(WebDom::of(document()));
+}
+
+struct WebListener {
+	node: Element,
+	kind: String,
+	callback: Closure<dyn FnMut(Event)>,
+}
+
+/// The DOM of the browser (through `web_sys`).
+pub struct WebDom {
+	document: Document,
+	listeners: RefCell<HashMap<usize, WebListener>>,
+	last_listener: Cell<usize>,
+}
+
+impl WebDom {
+	/// The DOM of the document of the window.
+	pub fn new() -> Result<Self, ElementError> {
+		Ok(Self::of(try_document()?))
+	}
+	pub fn of(document: Document) -> Self {
+		Self { document, listeners: RefCell::new(HashMap::new()), last_listener: Cell::new(0) }
+	}
+	/// The DOM of the document of the window that is shared by the components that are made without one.
+	///
+	/// # Panics
+	/// If there is no document (like [document()]).
+	pub fn shared() -> Rc<Self> {
+		SHARED.with(Rc::clone)
+	}
+	pub fn document(&self) -> &Document {
+		&self.document
+	}
+}
+
+/// Reads the data components need from a browser event.
+fn convert(event: &Event) -> DomEvent {
+	let mut converted = DomEvent { native: Some(event.clone()), ..DomEvent::new(&event.type_()) };
+	if let Some(mouse) = event.dyn_ref::<MouseEvent>() {
+		converted.position = Some(Vector::new(mouse.client_x() as f64, mouse.client_y() as f64));
+		converted.buttons = mouse.buttons();
+	}
+	if let Some(pointer) = event.dyn_ref::<PointerEvent>() {
+		converted.pointer_id = Some(pointer.pointer_id());
+	}
+	if let Some(input) = event.target().and_then(|target| target.dyn_into::<HtmlInputElement>().ok()) {
+		converted.value = Some(input.value());
+		converted.checked = Some(input.checked());
+	}
+	converted
+}
+
+/// Creates a bubbling browser event with the data of the event.
+fn create_native(event: &DomEvent) -> Option<Event> {
+	let pointer = event.position.is_some()
+		|| event.pointer_id.is_some()
+		|| matches!(event.kind.as_str(), "click" | "dblclick" | "auxclick" | "contextmenu");
+	if !pointer {
+		let init = EventInit::new();
+		init.set_bubbles(true);
+		return Event::new_with_event_init_dict(&event.kind, &init).ok();
+	}
+	let init = PointerEventInit::new();
+	init.set_bubbles(true);
+	init.set_cancelable(true);
+	if let Some(position) = event.position {
+		init.set_client_x(position.x as i32);
+		init.set_client_y(position.y as i32);
+	}
+	init.set_buttons(event.buttons);
+	init.set_pointer_id(event.pointer_id.unwrap_or(1));
+	PointerEvent::new_with_event_init_dict(&event.kind, &init).ok().map(Into::into)
+}
+
+impl DomNode for Element {
+	fn append(&self, child: &Self) -> Result<(), ElementError> {
+		self.append_child(child).or(Err(ElementError::InsertFailed))?;
+		Ok(())
+	}
+	fn detach(&self) {
+		self.remove();
+	}
+}
+
+impl Dom for WebDom {
+	type Node = Element;
+	type PointerEvent = PointerEvent;
+
+	fn create_element(&self, tag: &str) -> Result<Element, ElementError> {
+		self.document.create_element(tag).or(Err(ElementError::CreateFailed(tag.to_owned())))
+	}
+	fn tag(&self, node: &Element) -> String {
+		node.tag_name().to_ascii_lowercase()
+	}
+
+	fn attribute(&self, node: &Element, name: &str) -> Option<String> {
+		node.get_attribute(name)
+	}
+	fn set_attribute(&self, node: &Element, name: &str, value: &str) {
+		_ = node.set_attribute(name, value);
+	}
+	fn remove_attribute(&self, node: &Element, name: &str) {
+		_ = node.remove_attribute(name);
+	}
+
+	fn add_class(&self, node: &Element, class: &str) {
+		crate::elements::add_class(node, class);
+	}
+	fn remove_class(&self, node: &Element, class: &str) {
+		crate::elements::remove_class(node, class);
+	}
+	fn has_class(&self, node: &Element, class: &str) -> bool {
+		crate::elements::has_class(node, class)
+	}
+
+	fn style(&self, node: &Element, property: &str) -> Option<String> {
+		let value = node.dyn_ref::<HtmlElement>()?.style().get_property_value(property).ok()?;
+		(!value.is_empty()).then_some(value)
+	}
+	fn set_style(&self, node: &Element, property: &str, value: &str) -> Result<(), StyleError> {
+		let error = || StyleError { property: property.to_owned(), value: value.to_owned() };
+		let element = node.dyn_ref::<HtmlElement>().ok_or_else(error)?;
+		element.style().set_property(property, value).map_err(|_| error())
+	}
+	fn size(&self, node: &Element) -> Vector {
+		Vector::new(node.client_width() as f64, node.client_height() as f64)
+	}
+	fn register_default_style(&self, key: &str, css: &str) {
+		_ = StyleSheets::register_default(key, css);
+	}
+
+	fn text(&self, node: &Element) -> String {
+		node.text_content().unwrap_or_default()
+	}
+	fn set_text(&self, node: &Element, text: &str) {
+		node.set_text_content(Some(text));
+	}
+	fn value(&self, node: &Element) -> Option<String> {
+		Some(node.dyn_ref::<HtmlInputElement>()?.value())
+	}
+	fn set_value(&self, node: &Element, value: &str) {
+		let Some(input) = node.dyn_ref::<HtmlInputElement>() else { return };
+		input.set_value(value);
+	}
+	fn checked(&self, node: &Element) -> Option<bool> {
+		Some(node.dyn_ref::<HtmlInputElement>()?.checked())
+	}
+	fn set_checked(&self, node: &Element, checked: bool) {
+		let Some(input) = node.dyn_ref::<HtmlInputElement>() else { return };
+		input.set_checked(checked);
+	}
+
+	fn append_child(&self, parent: &Element, child: &Element) -> Result<(), ElementError> {
+		parent.append_child(child).or(Err(ElementError::InsertFailed))?;
+		Ok(())
+	}
+	fn insert_before(
+		&self,
+		parent: &Element,
+		child: &Element,
+		reference: Option<&Element>,
+	) -> Result<(), ElementError> {
+		parent
+			.insert_before(child, reference.map(|reference| reference.as_ref()))
+			.or(Err(ElementError::InsertFailed))?;
+		Ok(())
+	}
+	fn remove(&self, node: &Element) {
+		node.remove();
+	}
+	fn parent(&self, node: &Element) -> Option<Element> {
+		node.parent_element()
+	}
+	fn children(&self, node: &Element) -> Vec<Element> {
+		let children = node.child_nodes();
+		(0..children.length())
+			.filter_map(|index| children.item(index)?.dyn_into::<Element>().ok())
+			.collect()
+	}
+
+	fn add_listener(&self, node: &Element, kind: &str, mut handler: Box<dyn FnMut(&DomEvent)>) -> ListenerId {
+		let callback = Closure::<dyn FnMut(Event)>::new(move |event: Event| {
+			let converted = convert(&event);
+			handler(&converted);
+			if converted.propagation_stopped() {
+				event.stop_propagation();
+			}
+			if converted.default_prevented() {
+				event.prevent_default();
+			}
+		});
+		_ = node.add_event_listener_with_callback(kind, callback.as_ref().unchecked_ref());
+
+		let id = self.last_listener.get() + 1;
+		self.last_listener.set(id);
+		let listener = WebListener { node: node.clone(), kind: kind.to_owned(), callback };
+		self.listeners.borrow_mut().insert(id, listener);
+		ListenerId(id)
+	}
+	fn remove_listener(&self, id: ListenerId) {
+		let Some(listener) = self.listeners.borrow_mut().remove(&id.0) else { return };
+		_ = listener
+			.node
+			.remove_event_listener_with_callback(&listener.kind, listener.callback.as_ref().unchecked_ref());
+	}
+	/// Dispatches the browser's event of the [DomEvent] (or a bubbling one with its data).
+	///
+	/// The value (or checked state) of an input is set before its event is dispatched.
+	fn dispatch(&self, node: &Element, event: &DomEvent) -> bool {
+		if let Some(input) = node.dyn_ref::<HtmlInputElement>() {
+			if let Some(value) = &event.value {
+				input.set_value(value);
+			}
+			if let Some(checked) = event.checked {
+				input.set_checked(checked);
+			}
+		}
+		let Some(dispatched) = event.native.clone().or_else(|| create_native(event)) else { return false };
+		node.dispatch_event(&dispatched).unwrap_or(false)
+	}
+	/// The native event as a `PointerEvent` if it is a `MouseEvent`.
+	///
+	/// Clicks are `MouseEvent`s in some browsers (their pointer fields are then undefined).
+	fn pointer_event(event: &DomEvent) -> Option<PointerEvent> {
+		let native = event.native.as_ref()?;
+		native.is_instance_of::<MouseEvent>().then(|| native.clone().unchecked_into())
+	}
+	fn capture_pointer(&self, node: &Element, pointer_id: i32) {
+		_ = node.set_pointer_capture(pointer_id);
+	}
+}
+
+impl Drop for WebDom {
+	fn drop(&mut self) {
+		let ids: Vec<usize> = self.listeners.borrow().keys().copied().collect();
+		for id in ids {
+			self.remove_listener(ListenerId(id));
+		}
+	}
+}
@@ -1,16 +1,20 @@
 use anyhow::Result;
-use web_sys::Element;
 
 use super::{Component, ComponentContent};
+use crate::dom::{Dom, WebDom};
 
 /// Trait for using a Component as a trait object.
-pub trait DynamicComponent {
-	fn mount_in(&self, element: &Element) -> Result<()>;
+pub trait DynamicComponent<D: Dom = WebDom> {
+	fn mount_in(&self, element: &D::Node) -> Result<()>;
 	fn update(&self) -> Result<()>;
 	fn unmount(&self) -> Result<()>;
+	/// The name of the content type (for debugging).
+	fn type_name(&self) -> &'static str;
+	/// Visits this component and then its registered children (depth-first, with their depth below the start).
+	fn walk(&self, depth: usize, visit: &mut dyn FnMut(usize, &dyn DynamicComponent<D>));
 }
-impl<C: ComponentContent> DynamicComponent for Component<C> {
-	fn mount_in(&self, element: &Element) -> Result<()> {
+impl<C: ComponentContent<D>, D: Dom> DynamicComponent<D> for Component<C, D> {
+	fn mount_in(&self, element: &D::Node) -> Result<()> {
 		Component::mount_in(self, element)
 	}
 	fn update(&self) -> Result<()> {
@@ -19,4 +23,10 @@ impl<C: ComponentContent> DynamicComponent for Component<C> {
 	fn unmount(&self) -> Result<()> {
 		Component::unmount(self)
 	}
+	fn type_name(&self) -> &'static str {
+		Component::name(self)
+	}
+	fn walk(&self, depth: usize, visit: &mut dyn FnMut(usize, &dyn DynamicComponent<D>)) {
+		Component::walk(self, depth, visit)
+	}
 }
@@ -0,0 +1,184 @@
+use std::{
+	cell::RefCell,
+	collections::{HashMap, HashSet},
+};
+
+use wasm_bindgen::JsCast;
+use web_sys::{Document, Element, Node};
+
+/// A piece of text with placeholders: `Hello {{name}}!`.
+#[derive(Clone, PartialEq, Debug)]
+pub(super) enum Part {
+	Text(String),
+	Binding(String),
+}
+
+/// Splits the text into static text and `{{binding}}`s (names are trimmed).
+///
+/// An unclosed `{{` is kept as text.
+pub(super) fn parse(text: &str) -> Vec<Part> {
+	let mut parts = Vec::new();
+	let mut rest = text;
+	while let Some(start) = rest.find("{{") {
+		let Some(length) = rest[start + 2..].find("}}") else { break };
+		if start > 0 {
+			parts.push(Part::Text(rest[..start].to_owned()));
+		}
+		parts.push(Part::Binding(rest[start + 2..start + 2 + length].trim().to_owned()));
+		rest = &rest[start + 2 + length + 2..];
+	}
+	if !rest.is_empty() {
+		parts.push(Part::Text(rest.to_owned()));
+	}
+	parts
+}
+
+fn has_bindings(parts: &[Part]) -> bool {
+	parts.iter().any(|part| matches!(part, Part::Binding(_)))
+}
+
+/// An attribute with placeholders (it's rendered again when one of its bindings changes).
+struct AttributeBinding {
+	element: Element,
+	attribute: String,
+	parts: Vec<Part>,
+}
+
+/// The `{{binding}}`s of an instantiated template.
+pub(super) struct Bindings {
+	names: HashSet<String>,
+	values: RefCell<HashMap<String, String>>,
+	/// every binding in text has its own text node
+	texts: HashMap<String, Vec<Node>>,
+	attributes: Vec<AttributeBinding>,
+}
+
+impl Bindings {
+	/// Finds the placeholders in the text and attributes below the root.
+	///
+	/// Text placeholders are replaced by (empty) text nodes of their own, so they can be updated without touching anything else.
+	pub fn collect(root: &Node, document: &Document) -> Self {
+		let mut this = Self {
+			names: HashSet::new(),
+			values: RefCell::new(HashMap::new()),
+			texts: HashMap::new(),
+			attributes: Vec::new(),
+		};
+		this.collect_below(root, document);
+		this
+	}
+
+	fn collect_below(&mut self, parent: &Node, document: &Document) {
+		let children = parent.child_nodes();
+		let children: Vec<Node> = (0..children.length()).filter_map(|i| children.item(i)).collect();
+		for child in children {
+			match child.node_type() {
+				Node::TEXT_NODE => self.collect_text(parent, &child, document),
+				Node::ELEMENT_NODE => {
+					self.collect_attributes(child.unchecked_ref());
+					self.collect_below(&child, document);
+				},
+				_ => {},
+			}
+		}
+	}
+
+	fn collect_text(&mut self, parent: &Node, text: &Node, document: &Document) {
+		let parts = parse(&text.text_content().unwrap_or_default());
+		if !has_bindings(&parts) {
+			return;
+		}
+		for part in parts {
+			let (content, binding) = match part {
+				Part::Text(content) => (content, None),
+				Part::Binding(name) => (String::new(), Some(name)),
+			};
+			let node: Node = document.create_text_node(&content).into();
+			_ = parent.insert_before(&node, Some(text));
+			if let Some(name) = binding {
+				self.names.insert(name.clone());
+				self.texts.entry(name).or_default().push(node);
+			}
+		}
+		_ = parent.remove_child(text);
+	}
+
+	fn collect_attributes(&mut self, element: &Element) {
+		for attribute in element.get_attribute_names().iter().filter_map(|name| name.as_string()) {
+			let parts = parse(&element.get_attribute(&attribute).unwrap_or_default());
+			if !has_bindings(&parts) {
+				continue;
+			}
+			for part in &parts {
+				if let Part::Binding(name) = part {
+					self.names.insert(name.clone());
+				}
+			}
+			let binding = AttributeBinding { element: element.clone(), attribute, parts };
+			Self::render_attribute(&binding, &HashMap::new());
+			self.attributes.push(binding);
+		}
+	}
+
+	pub fn names(&self) -> impl Iterator<Item = &str> {
+		self.names.iter().map(String::as_str)
+	}
+	pub fn contains(&self, name: &str) -> bool {
+		self.names.contains(name)
+	}
+	pub fn get(&self, name: &str) -> Option<String> {
+		self.values.try_borrow().ok()?.get(name).cloned()
+	}
+
+	/// Updates the text and attributes that use the binding.
+	///
+	/// Returns `false` if there is no such binding.
+	pub fn set(&self, name: &str, value: &str) -> bool {
+		if !self.contains(name) {
+			return false;
+		}
+		let Ok(mut values) = self.values.try_borrow_mut() else { return false };
+		values.insert(name.to_owned(), value.to_owned());
+		for node in self.texts.get(name).into_iter().flatten() {
+			node.set_text_content(Some(value));
+		}
+		for binding in &self.attributes {
+			if binding.parts.contains(&Part::Binding(name.to_owned())) {
+				Self::render_attribute(binding, &values);
+			}
+		}
+		true
+	}
+
+	fn render_attribute(binding: &AttributeBinding, values: &HashMap<String, String>) {
+		let value: String = binding
+			.parts
+			.iter()
+			.map(|part| match part {
+				Part::Text(text) => text.as_str(),
+				Part::Binding(name) => values.get(name).map(String::as_str).unwrap_or_default(),
+			})
+			.collect();
+		_ = binding.element.set_attribute(&binding.attribute, &value);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parses_placeholders() {
+		assert_eq!(
+			parse("Hello {{ name }}, {{greeting}}! {{unclosed"),
+			vec![
+				Part::Text("Hello ".to_owned()),
+				Part::Binding("name".to_owned()),
+				Part::Text(", ".to_owned()),
+				Part::Binding("greeting".to_owned()),
+				Part::Text("! {{unclosed".to_owned()),
+			]
+		);
+		assert_eq!(parse("no bindings"), vec![Part::Text("no bindings".to_owned())]);
+	}
+}
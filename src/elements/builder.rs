@@ -0,0 +1,98 @@
+use wasm_bindgen::{convert::FromWasmAbi, JsCast};
+use web_sys::{Element, EventTarget, HtmlElement, Node};
+
+use super::add_class;
+use crate::events::{EventListener, EventListeners};
+
+/// Sets up an element with chained calls.
+///
+/// Collects the [EventListener]s it creates, so they can be stored (e.g. in your `ComponentContent`) like any other listener.
+///
+/// # Example
+/// ```text
+/// let (button, listeners) = div()
+///     .build()
+///     .class("button primary")
+///     .attr("role", "button")
+///     .style("width", "4px")
+///     .child(label("Click me"))
+///     .on_event("click", |event: PointerEvent| log(event))
+///     .finish();
+/// ```
+pub struct ElementBuilder<T: AsRef<Element>> {
+	element: T,
+	listeners: EventListeners,
+}
+
+impl<T: AsRef<Element>> ElementBuilder<T> {
+	pub fn new(element: T) -> Self {
+		Self { element, listeners: EventListeners::new() }
+	}
+
+	/// Adds the css classes (separated by whitespace); unlike [styled()](super::styled) this keeps the existing ones.
+	pub fn class(self, css: &str) -> Self {
+		add_class(self.element.as_ref(), css);
+		self
+	}
+	pub fn attr(self, name: &str, value: &str) -> Self {
+		self.element.as_ref().set_attribute(name, value).expect("Failed to set attribute of element.");
+		self
+	}
+	/// Sets the data attribute `data-{key}`.
+	pub fn data(self, key: &str, value: &str) -> Self {
+		self.attr(&format!("data-{key}"), value)
+	}
+	/// Replaces the content of the element with the text.
+	pub fn text(self, value: &str) -> Self {
+		self.element.as_ref().set_text_content(Some(value));
+		self
+	}
+	/// Appends the child.
+	pub fn child(self, child: impl AsRef<Node>) -> Self {
+		self.element.as_ref().append_child(child.as_ref()).expect("Failed to append child to element.");
+		self
+	}
+	/// Appends the child and takes over its listeners.
+	pub fn child_builder<C: AsRef<Element>>(mut self, child: ElementBuilder<C>) -> Self {
+		let (child, mut listeners) = child.finish();
+		self.listeners.append(&mut listeners);
+		self.child(AsRef::<Element>::as_ref(&child))
+	}
+	/// Handles the event on the element.
+	///
+	/// The listener is returned by [ElementBuilder::finish].
+	pub fn on_event<E: FromWasmAbi + 'static>(
+		mut self,
+		eventname: &'static str,
+		handler: impl FnMut(E) + 'static,
+	) -> Self {
+		let target: EventTarget = self.element.as_ref().clone().unchecked_into();
+		self.listeners.push(EventListener::new(target, eventname).with_handler(handler));
+		self
+	}
+
+	/// The element and the listeners that were created for it.
+	pub fn finish(self) -> (T, EventListeners) {
+		(self.element, self.listeners)
+	}
+}
+
+impl<T: AsRef<Element> + AsRef<HtmlElement>> ElementBuilder<T> {
+	/// Sets the inline style property (e.g. `width`).
+	pub fn style(self, property: &str, value: &str) -> Self {
+		AsRef::<HtmlElement>::as_ref(&self.element)
+			.style()
+			.set_property(property, value)
+			.expect("Failed to set style of element.");
+		self
+	}
+}
+
+/// Starts an [ElementBuilder] from an element, e.g. `div().build()`.
+pub trait Build: AsRef<Element> + Sized {
+	fn build(self) -> ElementBuilder<Self> {
+		ElementBuilder::new(self)
+	}
+}
+
+impl<T: AsRef<Element>> Build for T {}
@@ -0,0 +1,66 @@
+use std::ops::Deref;
+
+use web_sys::Element;
+
+/// Adds the css classes (separated by whitespace) to the element.
+///
+/// Unlike [styled()](super::styled) this keeps the classes the element already has.
+pub fn add_class(element: &Element, css: &str) {
+	let class_list = element.class_list();
+	for class in css.split_whitespace() {
+		_ = class_list.add_1(class);
+	}
+}
+/// Removes the css classes (separated by whitespace) from the element.
+pub fn remove_class(element: &Element, css: &str) {
+	let class_list = element.class_list();
+	for class in css.split_whitespace() {
+		_ = class_list.remove_1(class);
+	}
+}
+/// Adds the class if the element doesn't have it, removes it otherwise.
+///
+/// # Returns
+/// Whether the element has the class now.
+pub fn toggle_class(element: &Element, class: &str) -> bool {
+	element.class_list().toggle(class).unwrap_or_else(|_| has_class(element, class))
+}
+/// Adds or removes the class (so the element has it only if `enabled`).
+pub fn set_class(element: &Element, class: &str, enabled: bool) {
+	match enabled {
+		true => add_class(element, class),
+		false => remove_class(element, class),
+	}
+}
+pub fn has_class(element: &Element, class: &str) -> bool {
+	element.class_list().contains(class)
+}
+
+/// Sets each class only if its condition is true (and removes it otherwise).
+///
+/// # Example
+/// ```text
+/// set_classes(&button, &[("active", is_active), ("disabled", !enabled)]);
+/// ```
+pub fn set_classes(element: &Element, classes: &[(&str, bool)]) {
+	for (class, enabled) in classes {
+		set_class(element, class, *enabled);
+	}
+}
+
+/// Adds the css classes to the element (see [add_class()]).
+///
+/// # Example
+/// ```text
+/// let menu = with_class(styled(div(), "context-menu"), css);
+/// ```
+///
+/// # Returns
+/// The element, now with the classes.
+pub fn with_class<T>(element: T, css: &str) -> T
+where
+	T: Deref<Target = web_sys::HtmlElement>,
+{
+	add_class(&element, css);
+	element
+}
@@ -0,0 +1,160 @@
+use web_sys::{
+	HtmlAnchorElement, HtmlDialogElement, HtmlElement, HtmlHeadingElement, HtmlImageElement, HtmlInputElement,
+	HtmlLiElement, HtmlOListElement, HtmlOptionElement, HtmlParagraphElement, HtmlProgressElement,
+	HtmlSelectElement, HtmlSpanElement, HtmlTableCaptionElement, HtmlTableCellElement, HtmlTableElement,
+	HtmlTableRowElement, HtmlTableSectionElement, HtmlTextAreaElement, HtmlUListElement,
+};
+
+use super::{try_create, try_input};
+use crate::{common::Number, errors::ElementError};
+
+/// Defines a factory (that panics on failure) and its `try_` variant for elements without content.
+macro_rules! empty_element {
+	($($(#[$meta:meta])* $name:ident, $try_name:ident: $tag:literal => $element:ty;)*) => {$(
+		$(#[$meta])*
+		pub fn $name() -> $element {
+			$try_name().unwrap()
+		}
+		pub fn $try_name() -> Result<$element, ElementError> {
+			try_create($tag)
+		}
+	)*};
+}
+
+/// Defines a factory (that panics on failure) and its `try_` variant for elements that contain text.
+macro_rules! text_element {
+	($($(#[$meta:meta])* $name:ident, $try_name:ident: $tag:literal => $element:ty;)*) => {$(
+		$(#[$meta])*
+		pub fn $name(text: &str) -> $element {
+			$try_name(text).unwrap()
+		}
+		pub fn $try_name(text: &str) -> Result<$element, ElementError> {
+			let element = try_create::<$element>($tag)?;
+			element.set_text_content(Some(text));
+			Ok(element)
+		}
+	)*};
+}
+
+/// Defines a factory (that panics on failure) and its `try_` variant for `input` elements of a type with a text value.
+macro_rules! input_element {
+	($($(#[$meta:meta])* $name:ident, $try_name:ident: $kind:literal;)*) => {$(
+		$(#[$meta])*
+		pub fn $name(value: &str) -> HtmlInputElement {
+			$try_name(value).unwrap()
+		}
+		pub fn $try_name(value: &str) -> Result<HtmlInputElement, ElementError> {
+			let input = try_input($kind)?;
+			input.set_value(value);
+			Ok(input)
+		}
+	)*};
+}
+
+text_element! {
+	span, try_span: "span" => HtmlSpanElement;
+	p, try_p: "p" => HtmlParagraphElement;
+	h1, try_h1: "h1" => HtmlHeadingElement;
+	h2, try_h2: "h2" => HtmlHeadingElement;
+	h3, try_h3: "h3" => HtmlHeadingElement;
+	h4, try_h4: "h4" => HtmlHeadingElement;
+	h5, try_h5: "h5" => HtmlHeadingElement;
+	h6, try_h6: "h6" => HtmlHeadingElement;
+	li, try_li: "li" => HtmlLiElement;
+	/// A header cell of a table.
+	th, try_th: "th" => HtmlTableCellElement;
+	/// A data cell of a table.
+	td, try_td: "td" => HtmlTableCellElement;
+	caption, try_caption: "caption" => HtmlTableCaptionElement;
+}
+
+empty_element! {
+	ul, try_ul: "ul" => HtmlUListElement;
+	ol, try_ol: "ol" => HtmlOListElement;
+	table, try_table: "table" => HtmlTableElement;
+	thead, try_thead: "thead" => HtmlTableSectionElement;
+	tbody, try_tbody: "tbody" => HtmlTableSectionElement;
+	tfoot, try_tfoot: "tfoot" => HtmlTableSectionElement;
+	tr, try_tr: "tr" => HtmlTableRowElement;
+	/// A dropdown (add [option()]s to it).
+	select, try_select: "select" => HtmlSelectElement;
+	/// A dialog (show it with `show()`/`show_modal()`).
+	dialog, try_dialog: "dialog" => HtmlDialogElement;
+}
+
+input_element! {
+	password, try_password: "password";
+	search, try_search: "search";
+	/// The value is a date formatted as `yyyy-mm-dd` (or empty).
+	date, try_date: "date";
+}
+
+/// A generic element, for tags that don't have a factory (e.g. `"section"`).
+pub fn element(tag: &str) -> HtmlElement {
+	try_element(tag).unwrap()
+}
+pub fn try_element(tag: &str) -> Result<HtmlElement, ElementError> {
+	try_create(tag)
+}
+
+pub fn img(src: &str, alt: &str) -> HtmlImageElement {
+	try_img(src, alt).unwrap()
+}
+pub fn try_img(src: &str, alt: &str) -> Result<HtmlImageElement, ElementError> {
+	let img = try_create::<HtmlImageElement>("img")?;
+	img.set_src(src);
+	img.set_alt(alt);
+	Ok(img)
+}
+
+/// A link.
+pub fn a(href: &str, text: &str) -> HtmlAnchorElement {
+	try_a(href, text).unwrap()
+}
+pub fn try_a(href: &str, text: &str) -> Result<HtmlAnchorElement, ElementError> {
+	let a = try_create::<HtmlAnchorElement>("a")?;
+	a.set_href(href);
+	a.set_text_content(Some(text));
+	Ok(a)
+}
+
+/// An option of a [select()].
+pub fn option(value: &str, text: &str, selected: bool) -> HtmlOptionElement {
+	try_option(value, text, selected).unwrap()
+}
+pub fn try_option(value: &str, text: &str, selected: bool) -> Result<HtmlOptionElement, ElementError> {
+	let option = try_create::<HtmlOptionElement>("option")?;
+	option.set_value(value);
+	option.set_text(text);
+	option.set_selected(selected);
+	Ok(option)
+}
+
+pub fn textarea(value: &str) -> HtmlTextAreaElement {
+	try_textarea(value).unwrap()
+}
+pub fn try_textarea(value: &str) -> Result<HtmlTextAreaElement, ElementError> {
+	let textarea = try_create::<HtmlTextAreaElement>("textarea")?;
+	textarea.set_value(value);
+	Ok(textarea)
+}
+
+pub fn number<T: ToString>(value: &T) -> HtmlInputElement {
+	try_number(value).unwrap()
+}
+pub fn try_number<T: ToString>(value: &T) -> Result<HtmlInputElement, ElementError> {
+	let number = try_input("number")?;
+	number.set_value(&value.to_string());
+	Ok(number)
+}
+
+/// A progress bar at `value` of `max`.
+pub fn progress(value: Number, max: Number) -> HtmlProgressElement {
+	try_progress(value, max).unwrap()
+}
+pub fn try_progress(value: Number, max: Number) -> Result<HtmlProgressElement, ElementError> {
+	let progress = try_create::<HtmlProgressElement>("progress")?;
+	progress.set_max(max);
+	progress.set_value(value);
+	Ok(progress)
+}
@@ -0,0 +1,96 @@
+/// Builds a tree of elements from markup.
+///
+/// Expands to the same calls you would write by hand (the [elements](crate::elements) factories, an
+/// [ElementBuilder](crate::elements::ElementBuilder) per element and [on()](crate::elements::on) to mount children),
+/// so the result can be stored in a plain `ComponentContent`.
+///
+/// # Syntax
+/// ```text
+/// html! { listeners;
+///     div() as element [class("frame")] {
+///         canvas() as canvas [class("frame-canvas")]
+///         div() [class("frame-outline"), attr("role", "button"), @click = |event: PointerEvent| ..] {
+///             label("Title")
+///         }
+///     }
+/// }
+/// ```
+/// - An element is a factory from [elements](crate::elements) with its arguments (e.g. `div()`, `label("Title")`).
+/// - `as name` binds the element to a local variable (it's available after the macro).
+/// - `[..]` sets the element up; the entries are either methods of
+///   [ElementBuilder](crate::elements::ElementBuilder) (e.g. `class("a")`, `style("width", "4px")`)
+///   or event handlers (`@eventname = handler`).
+/// - `{..}` contains the children.
+/// - The first identifier (`listeners` above) is bound to the [EventListeners](crate::events::EventListeners) of all handlers.
+///   Store it as long as the handlers should be active.
+///
+/// Elements at the top level aren't mounted anywhere (so bind them to mount them yourself).
+#[macro_export]
+macro_rules! html {
+	($listeners:ident; $($tree:tt)*) => {
+		let mut $listeners = $crate::events::EventListeners::new();
+		$crate::__html_nodes!($listeners, []; $($tree)*);
+	};
+}
+
+/// Processes the nodes of [html!] one after the other.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __html_nodes {
+	($listeners:ident, [$($parent:ident)?];) => {};
+
+	// element: factory and arguments
+	($listeners:ident, $parent:tt; $tag:ident ($($argument:expr),* $(,)?) $($rest:tt)*) => {
+		$crate::__html_nodes!(@name $listeners, $parent, [$crate::elements::$tag($($argument),*)]; $($rest)*);
+	};
+
+	// optional name
+	(@name $listeners:ident, $parent:tt, [$($element:tt)*]; as $name:ident $($rest:tt)*) => {
+		$crate::__html_nodes!(@setup $listeners, $parent, [$($element)*], $name; $($rest)*);
+	};
+	(@name $listeners:ident, $parent:tt, [$($element:tt)*]; $($rest:tt)*) => {
+		$crate::__html_nodes!(@setup $listeners, $parent, [$($element)*], element; $($rest)*);
+	};
+
+	// optional setup
+	(@setup $listeners:ident, $parent:tt, [$($element:tt)*], $name:ident; [$($setup:tt)*] $($rest:tt)*) => {
+		let $name = $crate::__html_setup!($crate::elements::ElementBuilder::new($($element)*); $($setup)*);
+		$crate::__html_nodes!(@children $listeners, $parent, $name; $($rest)*);
+	};
+	(@setup $listeners:ident, $parent:tt, [$($element:tt)*], $name:ident; $($rest:tt)*) => {
+		let $name = $crate::elements::ElementBuilder::new($($element)*);
+		$crate::__html_nodes!(@children $listeners, $parent, $name; $($rest)*);
+	};
+
+	// optional children (then continue with the siblings)
+	(@children $listeners:ident, $parent:tt, $name:ident; {$($children:tt)*} $($rest:tt)*) => {
+		$crate::__html_nodes!(@finish $listeners, $parent, $name);
+		$crate::__html_nodes!($listeners, [$name]; $($children)*);
+		$crate::__html_nodes!($listeners, $parent; $($rest)*);
+	};
+	(@children $listeners:ident, $parent:tt, $name:ident; $($rest:tt)*) => {
+		$crate::__html_nodes!(@finish $listeners, $parent, $name);
+		$crate::__html_nodes!($listeners, $parent; $($rest)*);
+	};
+
+	(@finish $listeners:ident, [$($parent:ident)?], $name:ident) => {
+		let ($name, mut listeners) = $name.finish();
+		$listeners.append(&mut listeners);
+		$($crate::elements::on(&$parent, &$name);)?
+	};
+}
+
+/// Chains the setup entries of an element in [html!] onto its builder.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __html_setup {
+	($builder:expr;) => {
+		$builder
+	};
+	($builder:expr; @ $event:ident = $handler:expr $(, $($rest:tt)*)?) => {
+		$crate::__html_setup!($builder.on_event(stringify!($event), $handler); $($($rest)*)?)
+	};
+	($builder:expr; $method:ident ($($argument:expr),* $(,)?) $(, $($rest:tt)*)?) => {
+		$crate::__html_setup!($builder.$method($($argument),*); $($($rest)*)?)
+	};
+}
@@ -3,12 +3,59 @@ use std::{ops::Deref, str::FromStr};
 use result_or_err::ResultOrErr;
 use wasm_bindgen::JsCast;
 use web_sys::{
-	window, Element, HtmlCanvasElement, HtmlDivElement, HtmlElement, HtmlHrElement, HtmlInputElement,
+	window, Document, Element, HtmlCanvasElement, HtmlDivElement, HtmlElement, HtmlHrElement, HtmlInputElement,
 	HtmlLabelElement, HtmlTemplateElement, Node,
 };
 
+use crate::errors::ElementError;
+
+mod builder;
+pub use builder::*;
+
+mod classes;
+pub use classes::*;
+
+mod style;
+pub use style::*;
+
+mod html;
+
+mod factories;
+pub use factories::*;
+
+mod bindings;
+
+mod stylesheets;
+pub use stylesheets::*;
+
+mod theme;
+pub use theme::*;
+
+mod template;
+pub use template::*;
+
 pub fn document() -> web_sys::Document {
-	window().unwrap().document().unwrap()
+	try_document().unwrap()
+}
+/// Like [document()], but returns an error instead of panicking if there is no document (e.g. in a worker).
+pub fn try_document() -> Result<Document, ElementError> {
+	window().ok_or(ElementError::NoWindow)?.document().ok_or(ElementError::NoDocument)
+}
+
+/// Creates a new element with the tag and casts it to the element type `T` (e.g. `HtmlDivElement`).
+pub fn try_create<T: JsCast>(tag: &str) -> Result<T, ElementError> {
+	try_document()?
+		.create_element(tag)
+		.or(Err(ElementError::CreateFailed(tag.to_owned())))?
+		.dyn_into::<T>()
+		.or(Err(ElementError::WrongType(tag.to_owned())))
+}
+
+/// Creates a new `input` element of the type (e.g. `"checkbox"`).
+pub fn try_input(kind: &str) -> Result<HtmlInputElement, ElementError> {
+	let input = try_create::<HtmlInputElement>("input")?;
+	input.set_type(kind);
+	Ok(input)
 }
 
 /// Mounts the child in the parent element.
@@ -31,6 +78,8 @@ where
 
 /// Styles the element with the given css class.
 ///
+/// This replaces all classes of the element (use [with_class()] to keep them).
+///
 /// # Example
 /// ```rust
 /// let button = styled(div(), "myButtonClass");
@@ -67,59 +116,87 @@ where
 /// # Returns
 /// A new, unmounted, div.
 pub fn div() -> HtmlDivElement {
-	document().create_element("div").unwrap().dyn_into::<HtmlDivElement>().unwrap()
+	try_div().unwrap()
+}
+pub fn try_div() -> Result<HtmlDivElement, ElementError> {
+	try_create("div")
 }
 
 pub fn label(text: &str) -> HtmlLabelElement {
-	let label = document().create_element("label").unwrap().dyn_into::<HtmlLabelElement>().unwrap();
+	try_label(text).unwrap()
+}
+pub fn try_label(text: &str) -> Result<HtmlLabelElement, ElementError> {
+	let label = try_create::<HtmlLabelElement>("label")?;
 	label.set_text_content(Some(text));
-	label
+	Ok(label)
 }
 
 pub fn text(value: &str) -> HtmlInputElement {
-	let text = document().create_element("input").unwrap().dyn_into::<HtmlInputElement>().unwrap();
-	text.set_type("text");
+	try_text(value).unwrap()
+}
+pub fn try_text(value: &str) -> Result<HtmlInputElement, ElementError> {
+	let text = try_input("text")?;
 	text.set_value(value);
-	text
+	Ok(text)
 }
 
 pub fn checkbox(value: bool) -> HtmlInputElement {
-	let text = document().create_element("input").unwrap().dyn_into::<HtmlInputElement>().unwrap();
-	text.set_type("checkbox");
-	text.set_checked(value);
-	text
+	try_checkbox(value).unwrap()
+}
+pub fn try_checkbox(value: bool) -> Result<HtmlInputElement, ElementError> {
+	let checkbox = try_input("checkbox")?;
+	checkbox.set_checked(value);
+	Ok(checkbox)
 }
 
 pub fn color(value: &str) -> HtmlInputElement {
-	let color = document().create_element("input").unwrap().dyn_into::<HtmlInputElement>().unwrap();
-	color.set_type("color");
+	try_color(value).unwrap()
+}
+pub fn try_color(value: &str) -> Result<HtmlInputElement, ElementError> {
+	let color = try_input("color")?;
 	color.set_value(value);
-	color
+	Ok(color)
 }
 
 pub fn slider<T: FromStr + ToString + 'static>(value: &T, min: &T, max: &T, step: &T) -> HtmlInputElement {
-	let slider = document().create_element("input").unwrap().dyn_into::<HtmlInputElement>().unwrap();
-	slider.set_type("range");
+	try_slider(value, min, max, step).unwrap()
+}
+pub fn try_slider<T: FromStr + ToString + 'static>(
+	value: &T,
+	min: &T,
+	max: &T,
+	step: &T,
+) -> Result<HtmlInputElement, ElementError> {
+	let slider = try_input("range")?;
 	slider.set_min(&min.to_string());
 	slider.set_max(&max.to_string());
 	slider.set_step(&step.to_string());
 	slider.set_value(&value.to_string());
-	slider
+	Ok(slider)
 }
 
 pub fn canvas() -> HtmlCanvasElement {
-	document().create_element("canvas").unwrap().dyn_into::<HtmlCanvasElement>().unwrap()
+	try_canvas().unwrap()
+}
+pub fn try_canvas() -> Result<HtmlCanvasElement, ElementError> {
+	try_create("canvas")
 }
 
 pub fn hr() -> HtmlHrElement {
-	document().create_element("hr").unwrap().dyn_into::<HtmlHrElement>().unwrap()
+	try_hr().unwrap()
+}
+pub fn try_hr() -> Result<HtmlHrElement, ElementError> {
+	try_create("hr")
 }
 
 /// Returns a new node created from the template specified by [id].
 ///
 /// If there is no template with the specified [id] returns `Err(())`.
+///
+/// Use [Template] to get typed access to the elements in the template (and errors that say what went wrong).
 pub fn from_template(id: &str) -> Result<Node, ()> {
-	let template = document().get_element_by_id(id).ok_or(())?.dyn_into::<HtmlTemplateElement>().or_err(())?;
+	let template =
+		try_document().or_err(())?.get_element_by_id(id).ok_or(())?.dyn_into::<HtmlTemplateElement>().or_err(())?;
 	let new_node = template.content().clone_node_with_deep(true).unwrap();
 	Ok(new_node)
 }
@@ -0,0 +1,144 @@
+use std::fmt::Display;
+
+use web_sys::HtmlElement;
+
+use crate::{
+	common::{Color, Number, Transform},
+	errors::{ErrorHandler, StyleError},
+};
+
+/// A css length.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Length {
+	Px(Number),
+	Percent(Number),
+	Em(Number),
+	Rem(Number),
+	Vw(Number),
+	Vh(Number),
+	Auto,
+}
+
+impl Display for Length {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Length::Px(value) => write!(f, "{}px", value),
+			Length::Percent(value) => write!(f, "{}%", value),
+			Length::Em(value) => write!(f, "{}em", value),
+			Length::Rem(value) => write!(f, "{}rem", value),
+			Length::Vw(value) => write!(f, "{}vw", value),
+			Length::Vh(value) => write!(f, "{}vh", value),
+			Length::Auto => write!(f, "auto"),
+		}
+	}
+}
+
+/// Inline style properties to set on an element at once.
+///
+/// Collect the properties and [apply](Style::apply) them in one go.
+/// Failures are reported to an [ErrorHandler] instead of being ignored.
+///
+/// # Example
+/// ```text
+/// Style::new()
+///     .left(Length::Px(start.x))
+///     .top(Length::Px(start.y))
+///     .background_color(Color::white())
+///     .apply(&element, &error_handler);
+/// ```
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Style {
+	properties: Vec<(String, String)>,
+}
+
+impl Style {
+	pub fn new() -> Self {
+		Self { properties: Vec::new() }
+	}
+
+	/// Sets any property (e.g. `"border-radius"`).
+	///
+	/// Setting a property again replaces the earlier value.
+	pub fn property(mut self, name: &str, value: impl Display) -> Self {
+		let value = value.to_string();
+		match self.properties.iter_mut().find(|(existing, _)| existing == name) {
+			Some((_, existing)) => *existing = value,
+			None => self.properties.push((name.to_owned(), value)),
+		}
+		self
+	}
+
+	pub fn left(self, value: Length) -> Self {
+		self.property("left", value)
+	}
+	pub fn top(self, value: Length) -> Self {
+		self.property("top", value)
+	}
+	pub fn right(self, value: Length) -> Self {
+		self.property("right", value)
+	}
+	pub fn bottom(self, value: Length) -> Self {
+		self.property("bottom", value)
+	}
+	pub fn width(self, value: Length) -> Self {
+		self.property("width", value)
+	}
+	pub fn height(self, value: Length) -> Self {
+		self.property("height", value)
+	}
+	pub fn opacity(self, value: Number) -> Self {
+		self.property("opacity", value)
+	}
+	pub fn z_index(self, value: i32) -> Self {
+		self.property("z-index", value)
+	}
+	pub fn display(self, value: &str) -> Self {
+		self.property("display", value)
+	}
+	pub fn color(self, value: Color) -> Self {
+		self.property("color", value.to_rgb_string())
+	}
+	pub fn background_color(self, value: Color) -> Self {
+		self.property("background-color", value.to_rgb_string())
+	}
+	pub fn transform(self, value: Transform) -> Self {
+		let Transform { a, b, c, d, e, f } = value;
+		self.property("transform", format!("matrix({a}, {b}, {c}, {d}, {e}, {f})"))
+	}
+
+	pub fn properties(&self) -> impl Iterator<Item = (&str, &str)> {
+		self.properties.iter().map(|(name, value)| (name.as_str(), value.as_str()))
+	}
+	pub fn is_empty(&self) -> bool {
+		self.properties.is_empty()
+	}
+
+	/// Sets all properties on the element.
+	///
+	/// # Returns
+	/// The properties that could not be set (all of them are attempted).
+	pub fn try_apply(&self, element: &HtmlElement) -> Result<(), Vec<StyleError>> {
+		let style = element.style();
+		let errors: Vec<StyleError> = self
+			.properties
+			.iter()
+			.filter(|(name, value)| style.set_property(name, value).is_err())
+			.map(|(name, value)| StyleError { property: name.clone(), value: value.clone() })
+			.collect();
+		match errors.is_empty() {
+			true => Ok(()),
+			false => Err(errors),
+		}
+	}
+	/// Sets all properties on the element and reports the ones that could not be set to the handler.
+	///
+	/// # Returns
+	/// Whether all properties were set.
+	pub fn apply(&self, element: &HtmlElement, handler: &impl ErrorHandler<StyleError>) -> bool {
+		let Err(errors) = self.try_apply(element) else { return true };
+		for error in errors {
+			handler.handle(error);
+		}
+		false
+	}
+}
@@ -0,0 +1,140 @@
+use std::{
+	cell::{Cell, RefCell},
+	collections::HashMap,
+};
+
+use web_sys::Element;
+
+use super::{add_class, try_document};
+use crate::errors::ElementError;
+
+/// The attribute that marks the `<style>` elements of the registry (its value is the key).
+pub const STYLE_KEY_ATTRIBUTE: &str = "data-webbit-style";
+
+thread_local! {
+	static STYLES: RefCell<HashMap<String, Element>> = RefCell::new(HashMap::new());
+	static DEFAULTS_ENABLED: Cell<bool> = const { Cell::new(true) };
+	static LAST_SCOPE: Cell<usize> = const { Cell::new(0) };
+}
+
+/// The registry of the stylesheets that are injected into the document (as `<style>` elements in the head).
+///
+/// Every stylesheet has a key (e.g. the name of the component type it's for) and is injected only once.
+///
+/// # Default styles
+/// The built-in components register their default styles (see [styles](crate::components::styles)) when they are created.
+/// - To opt out, call [StyleSheets::set_defaults_enabled] with `false` before creating any component.
+/// - To override them, [register](StyleSheets::register) your own styles with the same key before creating the component,
+///   or [replace](StyleSheets::replace) them afterwards.
+pub struct StyleSheets;
+
+impl StyleSheets {
+	/// Injects the css with the key unless there already are styles with that key.
+	///
+	/// # Returns
+	/// Whether the css was injected.
+	pub fn register(key: &str, css: &str) -> Result<bool, ElementError> {
+		if Self::is_registered(key) {
+			return Ok(false);
+		}
+		let style = Self::inject(css)?;
+		_ = style.set_attribute(STYLE_KEY_ATTRIBUTE, key);
+		STYLES.with(|styles| styles.borrow_mut().insert(key.to_owned(), style));
+		Ok(true)
+	}
+	/// Like [StyleSheets::register], but does nothing if default styles are disabled.
+	pub fn register_default(key: &str, css: &str) -> Result<bool, ElementError> {
+		match Self::defaults_enabled() {
+			true => Self::register(key, css),
+			false => Ok(false),
+		}
+	}
+	/// Replaces the css with the key (or injects it if there is none).
+	pub fn replace(key: &str, css: &str) -> Result<(), ElementError> {
+		let existing = STYLES.with(|styles| styles.borrow().get(key).cloned());
+		match existing {
+			Some(style) => style.set_text_content(Some(css)),
+			None => _ = Self::register(key, css)?,
+		}
+		Ok(())
+	}
+	/// Removes the css with the key from the document.
+	///
+	/// # Returns
+	/// Whether there was css with the key.
+	pub fn remove(key: &str) -> bool {
+		let Some(style) = STYLES.with(|styles| styles.borrow_mut().remove(key)) else { return false };
+		style.remove();
+		true
+	}
+	pub fn is_registered(key: &str) -> bool {
+		STYLES.with(|styles| styles.borrow().contains_key(key))
+	}
+
+	/// Whether the built-in components register their default styles (default: `true`).
+	pub fn defaults_enabled() -> bool {
+		DEFAULTS_ENABLED.with(Cell::get)
+	}
+	/// Disables (or enables) the default styles of the built-in components.
+	///
+	/// This only affects components created afterwards (use [StyleSheets::remove] for the ones already registered).
+	pub fn set_defaults_enabled(enabled: bool) {
+		DEFAULTS_ENABLED.with(|defaults| defaults.set(enabled));
+	}
+
+	/// Injects css that only applies to the elements of one instance.
+	///
+	/// Use `:scope` in the css for the root of the instance and add the [class](ScopedStyle::class) to it:
+	/// ```text
+	/// let style = StyleSheets::scoped(":scope { width: 200px; } :scope .title { color: red; }")?;
+	/// style.apply_to(&element);
+	/// ```
+	/// The css is removed when the returned [ScopedStyle] is dropped.
+	pub fn scoped(css: &str) -> Result<ScopedStyle, ElementError> {
+		let scope = LAST_SCOPE.with(|last| {
+			last.set(last.get() + 1);
+			last.get()
+		});
+		let class = format!("webbit-scope-{scope}");
+		let style = Self::inject(&css.replace(":scope", &format!(".{class}")))?;
+		Ok(ScopedStyle { class, style })
+	}
+
+	fn inject(css: &str) -> Result<Element, ElementError> {
+		let document = try_document()?;
+		let style = document.create_element("style").or(Err(ElementError::CreateFailed("style".to_owned())))?;
+		style.set_text_content(Some(css));
+		let head = document.head().ok_or(ElementError::NoDocument)?;
+		head.append_child(&style).or(Err(ElementError::CreateFailed("style".to_owned())))?;
+		Ok(style)
+	}
+}
+
+/// Css for one instance (see [StyleSheets::scoped]).
+///
+/// The css is removed from the document when this is dropped.
+pub struct ScopedStyle {
+	class: String,
+	style: Element,
+}
+
+impl ScopedStyle {
+	/// The class that `:scope` refers to.
+	pub fn class(&self) -> &str {
+		&self.class
+	}
+	/// Makes the element the root of this scope.
+	pub fn apply_to(&self, element: &Element) {
+		add_class(element, &self.class);
+	}
+	/// Replaces the css (`:scope` refers to the same class).
+	pub fn set_css(&self, css: &str) {
+		self.style.set_text_content(Some(&css.replace(":scope", &format!(".{}", self.class))));
+	}
+}
+
+impl Drop for ScopedStyle {
+	fn drop(&mut self) {
+		self.style.remove();
+	}
+}
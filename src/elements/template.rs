@@ -0,0 +1,183 @@
+use std::{any::type_name, collections::HashMap};
+
+use wasm_bindgen::JsCast;
+use web_sys::{DocumentFragment, Element, HtmlTemplateElement};
+
+use super::{bindings::Bindings, try_document};
+use crate::errors::TemplateError;
+
+/// The attribute that names the slots of a template.
+pub const SLOT_ATTRIBUTE: &str = "data-slot";
+
+/// A new instance of a `<template>` with access to its named slots and bindings.
+///
+/// Slots are the elements of the template with a `data-slot="name"` attribute.
+/// Access them (cast to their `web_sys` type) with [Template::slot] or get all of them at once as a struct (see [template_slots!](crate::template_slots)).
+///
+/// Bindings are placeholders like `{{name}}` in the text and attribute values of the template.
+/// Give them values with [Template::instantiate_with] and update them in place with [Template::set]
+/// (the rest of the instance stays untouched). Bindings without a value are empty.
+///
+/// # Example
+/// ```text
+/// <template id="search-field">
+///     <div class="search"><input data-slot="input" type="search"><button data-slot="go">Go</button></div>
+/// </template>
+///
+/// let template = Template::instantiate("search-field")?;
+/// let root: HtmlDivElement = template.root()?;
+/// let input: HtmlInputElement = template.slot("input")?;
+///
+/// <template id="greeting"><p class="{{kind}}">Hello {{name}}!</p></template>
+///
+/// let template = Template::instantiate_with("greeting", &[("name", "World"), ("kind", "info")])?;
+/// template.set("name", "you")?;
+/// ```
+pub struct Template {
+	id: String,
+	content: DocumentFragment,
+	slots: HashMap<String, Element>,
+	bindings: Bindings,
+}
+
+impl Template {
+	/// Clones the content of the template with the id.
+	pub fn instantiate(id: &str) -> Result<Self, TemplateError> {
+		Self::instantiate_with(id, &[])
+	}
+	/// Clones the content of the template with the id and sets the bindings to the values.
+	pub fn instantiate_with(id: &str, values: &[(&str, &str)]) -> Result<Self, TemplateError> {
+		let document = try_document()?;
+		let template = document
+			.get_element_by_id(id)
+			.ok_or_else(|| TemplateError::MissingTemplate(id.to_owned()))?
+			.dyn_into::<HtmlTemplateElement>()
+			.or(Err(TemplateError::NotATemplate(id.to_owned())))?;
+		let content = template
+			.content()
+			.clone_node_with_deep(true)
+			.or(Err(TemplateError::NotATemplate(id.to_owned())))?
+			.unchecked_into::<DocumentFragment>();
+
+		let mut slots = HashMap::new();
+		let selector = format!("[{SLOT_ATTRIBUTE}]");
+		let elements = content.query_selector_all(&selector).or(Err(TemplateError::NotATemplate(id.to_owned())))?;
+		for i in 0..elements.length() {
+			let Some(element) = elements.item(i).and_then(|node| node.dyn_into::<Element>().ok()) else { continue };
+			let Some(name) = element.get_attribute(SLOT_ATTRIBUTE) else { continue };
+			// the first slot with a name wins
+			slots.entry(name).or_insert(element);
+		}
+
+		let bindings = Bindings::collect(&content, &document);
+
+		let this = Self { id: id.to_owned(), content, slots, bindings };
+		for (name, value) in values {
+			this.set(name, value)?;
+		}
+		Ok(this)
+	}
+
+	pub fn id(&self) -> &str {
+		&self.id
+	}
+	/// The names of all slots.
+	pub fn slot_names(&self) -> impl Iterator<Item = &str> {
+		self.slots.keys().map(String::as_str)
+	}
+
+	/// The names of all bindings.
+	pub fn binding_names(&self) -> impl Iterator<Item = &str> {
+		self.bindings.names()
+	}
+	/// The current value of the binding (`None` if it hasn't been set).
+	pub fn get(&self, binding: &str) -> Option<String> {
+		self.bindings.get(binding)
+	}
+	/// Updates the text and attributes that use the binding.
+	pub fn set(&self, binding: &str, value: &str) -> Result<(), TemplateError> {
+		match self.bindings.set(binding, value) {
+			true => Ok(()),
+			false => Err(TemplateError::UnknownBinding { template: self.id.clone(), binding: binding.to_owned() }),
+		}
+	}
+
+	/// The slot with the name, cast to `T`.
+	pub fn slot<T: JsCast>(&self, name: &str) -> Result<T, TemplateError> {
+		let element = self
+			.slots
+			.get(name)
+			.ok_or_else(|| TemplateError::MissingSlot { template: self.id.clone(), slot: name.to_owned() })?;
+		self.cast(element.clone(), name)
+	}
+	/// The first element of the template, cast to `T`.
+	///
+	/// Use this as the element of your component (it contains the slots if the template has only one root element).
+	pub fn root<T: JsCast>(&self) -> Result<T, TemplateError> {
+		let root = self.content.first_element_child().ok_or_else(|| TemplateError::NoRoot(self.id.clone()))?;
+		self.cast(root, "root")
+	}
+	/// The instantiated content (e.g. to mount all of it if the template has multiple root elements).
+	pub fn content(&self) -> &DocumentFragment {
+		&self.content
+	}
+
+	fn cast<T: JsCast>(&self, element: Element, slot: &str) -> Result<T, TemplateError> {
+		element.dyn_into::<T>().or(Err(TemplateError::WrongType {
+			template: self.id.clone(),
+			slot: slot.to_owned(),
+			expected: type_name::<T>(),
+		}))
+	}
+}
+
+/// Types that can be created from the slots of a [Template] (see [template_slots!](crate::template_slots)).
+pub trait FromTemplate: Sized {
+	fn from_template(template: &Template) -> Result<Self, TemplateError>;
+
+	/// Instantiates the template with the id and gets the slots from it.
+	///
+	/// # Returns
+	/// The slots and the template (e.g. to get its [root](Template::root)).
+	fn instantiate(id: &str) -> Result<(Self, Template), TemplateError> {
+		let template = Template::instantiate(id)?;
+		Ok((Self::from_template(&template)?, template))
+	}
+}
+
+/// Declares a struct with a field for each slot of a template and implements [FromTemplate] for it.
+///
+/// The field names are the slot names (`data-slot="name"`) and the field types are the `web_sys` types the slots are cast to.
+///
+/// # Example
+/// ```text
+/// template_slots! {
+///     pub struct SearchField {
+///         root: HtmlDivElement,
+///         input: HtmlInputElement,
+///         go: HtmlButtonElement,
+///     }
+/// }
+///
+/// let (slots, _) = SearchField::instantiate("search-field")?;
+/// ```
+/// A field named `root` is the [root](Template::root) of the template (unless the template has a slot named `root`).
+#[macro_export]
+macro_rules! template_slots {
+	($(#[$meta:meta])* $visibility:vis struct $name:ident { $($field_visibility:vis $field:ident: $type:ty),* $(,)? }) => {
+		$(#[$meta])*
+		$visibility struct $name {
+			$($field_visibility $field: $type,)*
+		}
+		impl $crate::elements::FromTemplate for $name {
+			fn from_template(template: &$crate::elements::Template) -> Result<Self, $crate::errors::TemplateError> {
+				Ok(Self {
+					$($field: match (stringify!($field), template.slot_names().any(|name| name == stringify!($field))) {
+						("root", false) => template.root::<$type>()?,
+						(slot, _) => template.slot::<$type>(slot)?,
+					},)*
+				})
+			}
+		}
+	};
+}
@@ -0,0 +1,288 @@
+use std::{
+	cell::{Cell, RefCell},
+	collections::BTreeMap,
+	rc::Rc,
+};
+
+use wasm_bindgen::JsCast;
+use web_sys::HtmlElement;
+
+use super::{try_document, Length, Style};
+use crate::{common::Color, errors::StyleError};
+
+/// The prefix of the css custom properties a [Theme] sets.
+pub const THEME_PREFIX: &str = "--webbit";
+
+/// Named design tokens (colors, spacing, radii and fonts) that are written to css custom properties.
+///
+/// Every token becomes a variable `--webbit-<kind>-<name>`, e.g. the color `primary` becomes `--webbit-color-primary`.
+/// Refer to them in css with `var(--webbit-color-primary)` (see [color_var] and friends).
+///
+/// # Example
+/// ```text
+/// let brand = Theme::light("brand").with_color("primary", Color::parse("#e11d48")?);
+/// Themes::set(brand)?;
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct Theme {
+	pub name: String,
+	colors: BTreeMap<String, Color>,
+	spacing: BTreeMap<String, Length>,
+	radii: BTreeMap<String, Length>,
+	fonts: BTreeMap<String, String>,
+}
+
+impl Theme {
+	/// An empty theme (without any tokens).
+	pub fn new(name: &str) -> Self {
+		Self {
+			name: name.to_owned(),
+			colors: BTreeMap::new(),
+			spacing: BTreeMap::new(),
+			radii: BTreeMap::new(),
+			fonts: BTreeMap::new(),
+		}
+	}
+	/// The light theme with all tokens the built-in components use.
+	pub fn light(name: &str) -> Self {
+		Self::with_defaults(name)
+			.with_color("background", Color::white())
+			.with_color("surface", Color::from_rgb_u32(0xfafafa))
+			.with_color("hover", Color::from_rgb_u32(0xf4f4f5))
+			.with_color("text", Color::from_rgb_u32(0x18181b))
+			.with_color("border", Color::from_rgb_u32(0xd4d4d8))
+			.with_color("primary", Color::from_rgb_u32(0x3b82f6))
+			.with_color("shadow", Color::black().with_alpha(0.15))
+	}
+	/// The dark theme with all tokens the built-in components use.
+	pub fn dark(name: &str) -> Self {
+		Self::with_defaults(name)
+			.with_color("background", Color::from_rgb_u32(0x18181b))
+			.with_color("surface", Color::from_rgb_u32(0x27272a))
+			.with_color("hover", Color::from_rgb_u32(0x3f3f46))
+			.with_color("text", Color::from_rgb_u32(0xf4f4f5))
+			.with_color("border", Color::from_rgb_u32(0x52525b))
+			.with_color("primary", Color::from_rgb_u32(0x60a5fa))
+			.with_color("shadow", Color::black().with_alpha(0.5))
+	}
+	fn with_defaults(name: &str) -> Self {
+		Self::new(name)
+			.with_spacing("small", Length::Px(4.0))
+			.with_spacing("medium", Length::Px(8.0))
+			.with_spacing("large", Length::Px(16.0))
+			.with_radius("small", Length::Px(4.0))
+			.with_radius("medium", Length::Px(6.0))
+			.with_font("body", "system-ui, sans-serif")
+			.with_font("mono", "ui-monospace, monospace")
+	}
+
+	pub fn with_color(mut self, name: &str, color: Color) -> Self {
+		self.colors.insert(name.to_owned(), color);
+		self
+	}
+	pub fn with_spacing(mut self, name: &str, length: Length) -> Self {
+		self.spacing.insert(name.to_owned(), length);
+		self
+	}
+	pub fn with_radius(mut self, name: &str, length: Length) -> Self {
+		self.radii.insert(name.to_owned(), length);
+		self
+	}
+	/// Adds a font token (the value is a css `font-family`).
+	pub fn with_font(mut self, name: &str, family: &str) -> Self {
+		self.fonts.insert(name.to_owned(), family.to_owned());
+		self
+	}
+
+	pub fn color(&self, name: &str) -> Option<&Color> {
+		self.colors.get(name)
+	}
+	pub fn spacing(&self, name: &str) -> Option<&Length> {
+		self.spacing.get(name)
+	}
+	pub fn radius(&self, name: &str) -> Option<&Length> {
+		self.radii.get(name)
+	}
+	pub fn font(&self, name: &str) -> Option<&str> {
+		self.fonts.get(name).map(String::as_str)
+	}
+
+	/// The css custom properties of all tokens (as `(variable, value)`).
+	pub fn variables(&self) -> Vec<(String, String)> {
+		let colors = self.colors.iter().map(|(name, color)| (variable("color", name), color.to_rgb_string()));
+		let spacing = self.spacing.iter().map(|(name, length)| (variable("spacing", name), length.to_string()));
+		let radii = self.radii.iter().map(|(name, length)| (variable("radius", name), length.to_string()));
+		let fonts = self.fonts.iter().map(|(name, family)| (variable("font", name), family.clone()));
+		colors.chain(spacing).chain(radii).chain(fonts).collect()
+	}
+	/// The variables as inline [Style].
+	pub fn to_style(&self) -> Style {
+		self.variables().into_iter().fold(Style::new(), |style, (name, value)| style.property(&name, value))
+	}
+	/// Writes the variables onto the element (they apply to everything below it).
+	///
+	/// Use [Themes::set] to switch the theme of the whole document and notify the components.
+	pub fn try_apply(&self, root: &HtmlElement) -> Result<(), Vec<StyleError>> {
+		self.to_style().try_apply(root)
+	}
+	/// Removes the variables from the element.
+	pub fn remove_from(&self, root: &HtmlElement) {
+		let style = root.style();
+		for (name, _) in self.variables() {
+			_ = style.remove_property(&name);
+		}
+	}
+}
+
+fn variable(kind: &str, name: &str) -> String {
+	format!("{THEME_PREFIX}-{kind}-{name}")
+}
+/// `var(--webbit-color-<name>)`
+pub fn color_var(name: &str) -> String {
+	format!("var({})", variable("color", name))
+}
+/// `var(--webbit-spacing-<name>)`
+pub fn spacing_var(name: &str) -> String {
+	format!("var({})", variable("spacing", name))
+}
+/// `var(--webbit-radius-<name>)`
+pub fn radius_var(name: &str) -> String {
+	format!("var({})", variable("radius", name))
+}
+/// `var(--webbit-font-<name>)`
+pub fn font_var(name: &str) -> String {
+	format!("var({})", variable("font", name))
+}
+
+type Subscriber = Rc<RefCell<Box<dyn FnMut(&Theme)>>>;
+
+thread_local! {
+	static CURRENT: RefCell<Option<Theme>> = const { RefCell::new(None) };
+	static ROOT: RefCell<Option<HtmlElement>> = const { RefCell::new(None) };
+	static SUBSCRIBERS: RefCell<Vec<(usize, Subscriber)>> = const { RefCell::new(Vec::new()) };
+	static LAST_SUBSCRIPTION: Cell<usize> = const { Cell::new(0) };
+}
+
+/// The theme of the document, which can be switched at runtime.
+///
+/// The variables are written onto the root element (the `<html>` element unless [set](Themes::set_root) otherwise).
+pub struct Themes;
+
+impl Themes {
+	/// Replaces the variables of the current theme with the ones of the theme and notifies the subscribers.
+	///
+	/// The subscribers are notified even if some variables could not be set.
+	///
+	/// # Returns
+	/// The variables that could not be set.
+	pub fn set(theme: Theme) -> Result<(), Vec<StyleError>> {
+		let result = match Self::root() {
+			Some(root) => {
+				if let Some(current) = Self::current() {
+					current.remove_from(&root);
+				}
+				theme.try_apply(&root)
+			},
+			None => Ok(()),
+		};
+		CURRENT.with(|current| *current.borrow_mut() = Some(theme.clone()));
+		Self::notify(&theme);
+		result
+	}
+	/// The theme that was [set](Themes::set) last.
+	pub fn current() -> Option<Theme> {
+		CURRENT.with(|current| current.borrow().clone())
+	}
+
+	/// Moves the variables of the current theme to another root element.
+	pub fn set_root(root: HtmlElement) -> Result<(), Vec<StyleError>> {
+		let current = Self::current();
+		if let (Some(theme), Some(old)) = (&current, Self::root()) {
+			theme.remove_from(&old);
+		}
+		ROOT.with(|r| *r.borrow_mut() = Some(root.clone()));
+		match current {
+			Some(theme) => theme.try_apply(&root),
+			None => Ok(()),
+		}
+	}
+	/// The element the variables are written onto.
+	pub fn root() -> Option<HtmlElement> {
+		if let Some(root) = ROOT.with(|root| root.borrow().clone()) {
+			return Some(root);
+		}
+		try_document().ok()?.document_element()?.dyn_into::<HtmlElement>().ok()
+	}
+
+	/// Calls the handler with the new theme whenever it is switched.
+	///
+	/// The handler is removed when the returned [ThemeSubscription] is dropped.
+	pub fn subscribe(handler: impl FnMut(&Theme) + 'static) -> ThemeSubscription {
+		let id = LAST_SUBSCRIPTION.with(|last| {
+			last.set(last.get() + 1);
+			last.get()
+		});
+		let handler: Subscriber = Rc::new(RefCell::new(Box::new(handler)));
+		SUBSCRIBERS.with(|subscribers| subscribers.borrow_mut().push((id, handler)));
+		ThemeSubscription { id }
+	}
+
+	fn notify(theme: &Theme) {
+		// handlers may (un)subscribe, so they are called without borrowing the list
+		let subscribers: Vec<Subscriber> =
+			SUBSCRIBERS.with(|subscribers| subscribers.borrow().iter().map(|(_, handler)| handler.clone()).collect());
+		for subscriber in subscribers {
+			// a handler that switches the theme itself isn't called again
+			let Ok(mut handler) = subscriber.try_borrow_mut() else { continue };
+			handler(theme);
+		}
+	}
+}
+
+/// A handler that is notified when the theme is switched (see [Themes::subscribe]).
+///
+/// The handler is removed when this is dropped.
+pub struct ThemeSubscription {
+	id: usize,
+}
+
+impl Drop for ThemeSubscription {
+	fn drop(&mut self) {
+		SUBSCRIBERS.with(|subscribers| subscribers.borrow_mut().retain(|(id, _)| *id != self.id));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn writes_variables() {
+		let theme = Theme::new("test")
+			.with_color("primary", Color::rgb(1.0, 0.0, 0.0))
+			.with_spacing("small", Length::Px(4.0))
+			.with_radius("small", Length::Rem(0.25))
+			.with_font("body", "serif");
+		let variables = theme.variables();
+		assert_eq!(variables[1..], [
+			("--webbit-spacing-small".to_owned(), "4px".to_owned()),
+			("--webbit-radius-small".to_owned(), "0.25rem".to_owned()),
+			("--webbit-font-body".to_owned(), "serif".to_owned()),
+		]);
+		assert_eq!(variables[0].0, "--webbit-color-primary");
+		assert_eq!(color_var("primary"), "var(--webbit-color-primary)");
+	}
+
+	#[test]
+	fn notifies_subscribers() {
+		let seen = Rc::new(RefCell::new(Vec::new()));
+		let subscription = Themes::subscribe({
+			let seen = seen.clone();
+			move |theme| seen.borrow_mut().push(theme.name.clone())
+		});
+		Themes::notify(&Theme::light("light"));
+		drop(subscription);
+		Themes::notify(&Theme::dark("dark"));
+		assert_eq!(*seen.borrow(), vec!["light".to_owned()]);
+	}
+}
@@ -0,0 +1,28 @@
+use std::{error::Error, fmt::Display};
+
+/// The reason a string could not be parsed into a [Color](crate::common::Color).
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColorParseError {
+	/// The string was empty (or only whitespace).
+	Empty,
+	/// The string started with `#` but was not a valid hex color.
+	InvalidHex(String),
+	/// The string looked like a color function (e.g. `rgb(...)`) but its arguments were invalid.
+	InvalidFunction(String),
+	/// The color function is not supported.
+	UnknownFunction(String),
+	/// The string is not a known color name.
+	UnknownName(String),
+}
+impl Error for ColorParseError {}
+impl Display for ColorParseError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			ColorParseError::Empty => write!(f, "empty color"),
+			ColorParseError::InvalidHex(value) => write!(f, "invalid hex color \"{}\"", value),
+			ColorParseError::InvalidFunction(value) => write!(f, "invalid color function \"{}\"", value),
+			ColorParseError::UnknownFunction(value) => write!(f, "unknown color function \"{}\"", value),
+			ColorParseError::UnknownName(value) => write!(f, "unknown color name \"{}\"", value),
+		}
+	}
+}
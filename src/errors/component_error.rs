@@ -0,0 +1,49 @@
+use std::{error::Error, fmt::Display};
+
+use super::{ErrorHandler, TracksEnvironment};
+
+/// The step of the lifecycle of a component (see [ComponentContent](crate::ComponentContent)).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LifecycleStage {
+	Mount,
+	Unmount,
+	Update,
+	Destroy,
+}
+impl Display for LifecycleStage {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			LifecycleStage::Mount => write!(f, "mount"),
+			LifecycleStage::Unmount => write!(f, "unmount"),
+			LifecycleStage::Update => write!(f, "update"),
+			LifecycleStage::Destroy => write!(f, "destroy"),
+		}
+	}
+}
+
+/// A step of the lifecycle that failed for the component (see [Component](crate::Component)).
+#[derive(Debug)]
+pub struct ComponentError {
+	/// the name of the component's content type
+	pub component: String,
+	pub stage: LifecycleStage,
+	pub error: anyhow::Error,
+}
+impl Error for ComponentError {}
+impl Display for ComponentError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "failed to {} component \"{}\": {}", self.stage, self.component, self.error)
+	}
+}
+
+/// An [ErrorHandler] for [ComponentError]s that can be [cloned](TracksEnvironment::clone_for) for the children of a component.
+///
+/// Built-in components take one (through their `with_error_handler`) to report the failures of the components they create.
+pub trait ComponentErrorHandler:
+	ErrorHandler<ComponentError> + TracksEnvironment<EnvironmentType = String> + 'static
+{
+}
+impl<H: ErrorHandler<ComponentError> + TracksEnvironment<EnvironmentType = String> + 'static>
+	ComponentErrorHandler for H
+{
+}
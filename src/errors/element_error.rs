@@ -0,0 +1,28 @@
+use std::{error::Error, fmt::Display};
+
+/// The reason an element could not be created (see the `try_` factories in [elements](crate::elements)).
+#[derive(Debug, Clone, PartialEq)]
+pub enum ElementError {
+	/// There is no `window` (e.g. in a worker).
+	NoWindow,
+	/// The window has no `document`.
+	NoDocument,
+	/// `document.createElement` failed for the tag.
+	CreateFailed(String),
+	/// The created element didn't have the expected type (the tag is given).
+	WrongType(String),
+	/// A node could not be inserted (e.g. into itself or before a node of another parent).
+	InsertFailed,
+}
+impl Error for ElementError {}
+impl Display for ElementError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			ElementError::NoWindow => write!(f, "there is no window"),
+			ElementError::NoDocument => write!(f, "the window has no document"),
+			ElementError::CreateFailed(tag) => write!(f, "failed to create element \"{}\"", tag),
+			ElementError::WrongType(tag) => write!(f, "element \"{}\" has an unexpected type", tag),
+			ElementError::InsertFailed => write!(f, "failed to insert node"),
+		}
+	}
+}
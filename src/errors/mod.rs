@@ -15,3 +15,21 @@ pub use context_in_use_error::*;
 
 mod string_environment;
 pub use string_environment::*;
+
+mod color_parse_error;
+pub use color_parse_error::*;
+
+mod element_error;
+pub use element_error::*;
+
+mod template_error;
+pub use template_error::*;
+
+mod style_error;
+pub use style_error::*;
+
+mod update_error;
+pub use update_error::*;
+
+mod component_error;
+pub use component_error::*;
@@ -0,0 +1,14 @@
+use std::{error::Error, fmt::Display};
+
+/// An inline style property that could not be set (see [Style](crate::elements::Style)).
+#[derive(Debug, Clone, PartialEq)]
+pub struct StyleError {
+	pub property: String,
+	pub value: String,
+}
+impl Error for StyleError {}
+impl Display for StyleError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "failed to set style \"{}: {}\"", self.property, self.value)
+	}
+}
@@ -0,0 +1,54 @@
+use std::{error::Error, fmt::Display};
+
+use super::ElementError;
+
+/// The reason a template could not be instantiated (see [Template](crate::elements::Template)).
+#[derive(Debug, Clone, PartialEq)]
+pub enum TemplateError {
+	/// The document isn't available.
+	Element(ElementError),
+	/// There is no element with the id.
+	MissingTemplate(String),
+	/// The element with the id is not a `<template>`.
+	NotATemplate(String),
+	/// The template (id) has no element (only text or nothing at all).
+	NoRoot(String),
+	/// The template (id) has no element with `data-slot="{slot}"`.
+	MissingSlot { template: String, slot: String },
+	/// The slot (or root) of the template (id) is not of the `expected` type.
+	WrongType { template: String, slot: String, expected: &'static str },
+	/// The template (id) has no `{{binding}}` with the name.
+	UnknownBinding { template: String, binding: String },
+}
+impl Error for TemplateError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			TemplateError::Element(error) => Some(error),
+			_ => None,
+		}
+	}
+}
+impl Display for TemplateError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			TemplateError::Element(error) => write!(f, "{}", error),
+			TemplateError::MissingTemplate(id) => write!(f, "there is no template \"{}\"", id),
+			TemplateError::NotATemplate(id) => write!(f, "element \"{}\" is not a template", id),
+			TemplateError::NoRoot(id) => write!(f, "template \"{}\" has no element", id),
+			TemplateError::MissingSlot { template, slot } => {
+				write!(f, "template \"{}\" has no slot \"{}\"", template, slot)
+			},
+			TemplateError::WrongType { template, slot, expected } => {
+				write!(f, "slot \"{}\" of template \"{}\" is not a {}", slot, template, expected)
+			},
+			TemplateError::UnknownBinding { template, binding } => {
+				write!(f, "template \"{}\" has no binding \"{}\"", template, binding)
+			},
+		}
+	}
+}
+impl From<ElementError> for TemplateError {
+	fn from(value: ElementError) -> Self {
+		TemplateError::Element(value)
+	}
+}
@@ -0,0 +1,16 @@
+use std::{error::Error, fmt::Display};
+
+/// A component that failed to [update](crate::ComponentContent::update) (e.g. when an [Effect](crate::reactive::Effect) re-rendered it).
+#[derive(Debug)]
+pub struct UpdateError(pub anyhow::Error);
+impl Error for UpdateError {}
+impl Display for UpdateError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "failed to update component: {}", self.0)
+	}
+}
+impl From<anyhow::Error> for UpdateError {
+	fn from(value: anyhow::Error) -> Self {
+		UpdateError(value)
+	}
+}
@@ -0,0 +1,59 @@
+use wasm_bindgen::convert::FromWasmAbi;
+
+use super::EventListener;
+
+/// A collection of [EventListener]s (for different event types).
+///
+/// Like a single `EventListener`, the handlers are removed when this is dropped.
+/// So keep it around as long as the handlers should be active (e.g. in your `ComponentContent`) or call `forget()` on it.
+#[derive(Default)]
+pub struct EventListeners {
+	listeners: Vec<Box<dyn AnyEventListener>>,
+}
+
+impl EventListeners {
+	pub fn new() -> Self {
+		Self { listeners: Vec::new() }
+	}
+
+	pub fn push<E: FromWasmAbi + 'static>(&mut self, listener: EventListener<E>) {
+		self.listeners.push(Box::new(listener));
+	}
+	/// Takes over all listeners of the other collection.
+	pub fn append(&mut self, other: &mut Self) {
+		self.listeners.append(&mut other.listeners);
+	}
+
+	pub fn len(&self) -> usize {
+		self.listeners.len()
+	}
+	pub fn is_empty(&self) -> bool {
+		self.listeners.is_empty()
+	}
+
+	/// Drops this object without removing the handlers (see [EventListener::forget]).
+	pub fn forget(self) {
+		for listener in self.listeners {
+			listener.forget_boxed();
+		}
+	}
+}
+
+impl<E: FromWasmAbi + 'static> From<EventListener<E>> for EventListeners {
+	fn from(value: EventListener<E>) -> Self {
+		let mut listeners = Self::new();
+		listeners.push(value);
+		listeners
+	}
+}
+
+/// Makes [EventListener]s with different event types storable together.
+trait AnyEventListener {
+	fn forget_boxed(self: Box<Self>);
+}
+
+impl<E: FromWasmAbi + 'static> AnyEventListener for EventListener<E> {
+	fn forget_boxed(self: Box<Self>) {
+		(*self).forget();
+	}
+}
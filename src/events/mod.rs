@@ -1,6 +1,9 @@
 mod event_listener;
 pub use event_listener::*;
 
+mod event_listeners;
+pub use event_listeners::*;
+
 mod shared_event_listener;
 pub use shared_event_listener::*;
 
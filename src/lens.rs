@@ -0,0 +1,54 @@
+use std::rc::Rc;
+
+/// Accesses a field `F` inside of data `T` (both for reading and writing).
+///
+/// Used to [bind](crate::Binding) a part of a [Context](crate::Context) to a widget.
+/// Use [lens!](crate::lens!) to make one for a (nested) field.
+pub struct Lens<T, F> {
+	get: Rc<dyn Fn(&T) -> &F>,
+	get_mut: Rc<dyn Fn(&mut T) -> &mut F>,
+}
+
+impl<T, F> Lens<T, F> {
+	pub fn new(get: impl Fn(&T) -> &F + 'static, get_mut: impl Fn(&mut T) -> &mut F + 'static) -> Self {
+		Self { get: Rc::new(get), get_mut: Rc::new(get_mut) }
+	}
+
+	pub fn get<'d>(&self, data: &'d T) -> &'d F {
+		(self.get)(data)
+	}
+	pub fn get_mut<'d>(&self, data: &'d mut T) -> &'d mut F {
+		(self.get_mut)(data)
+	}
+
+	/// A lens to a field `G` of the field `F`.
+	pub fn then<G>(&self, inner: Lens<F, G>) -> Lens<T, G>
+	where
+		T: 'static,
+		F: 'static,
+		G: 'static,
+	{
+		let (get, get_mut) = (self.get.clone(), self.get_mut.clone());
+		let (inner_get, inner_get_mut) = (inner.get, inner.get_mut);
+		Lens::new(move |data| inner_get(get(data)), move |data| inner_get_mut(get_mut(data)))
+	}
+}
+
+impl<T, F> Clone for Lens<T, F> {
+	fn clone(&self) -> Self {
+		Self { get: self.get.clone(), get_mut: self.get_mut.clone() }
+	}
+}
+
+/// Makes a [Lens] to a (nested) field.
+///
+/// # Example
+/// ```text
+/// let zoom = lens!(Model, settings.zoom); // Lens<Model, f64>
+/// ```
+#[macro_export]
+macro_rules! lens {
+	($type:ty, $($field:tt).+) => {
+		$crate::Lens::new(|data: &$type| &data.$($field).+, |data: &mut $type| &mut data.$($field).+)
+	};
+}
@@ -3,12 +3,15 @@ use std::{
 	ops::Deref,
 };
 
+pub mod animation;
 pub mod common;
 pub mod components;
+pub mod dom;
 pub mod elements;
 pub mod errors;
 pub mod events;
 pub mod io;
+pub mod reactive;
 
 mod component;
 pub use component::*;
@@ -22,6 +25,12 @@ pub use component_content::*;
 mod context;
 pub use context::*;
 
+mod lens;
+pub use lens::*;
+
+mod binding;
+pub use binding::*;
+
 #[cfg(test)]
 mod tests {
 	use super::*;
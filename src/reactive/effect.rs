@@ -0,0 +1,173 @@
+use std::{
+	cell::RefCell,
+	rc::{Rc, Weak},
+};
+
+use super::runtime::Observer;
+use crate::{
+	errors::{ErrorHandler, UpdateError},
+	ComponentContent,
+};
+
+/// Code that runs right away and reruns whenever a [Signal](super::Signal) (or [Memo](super::Memo)) it read changes.
+///
+/// The effect stops when it is dropped (or [disposed](Effect::dispose)).
+/// Effects that capture an `Rc` of their component should be stored in its [Effects] and disposed in `destroy()`,
+/// just like event handlers (otherwise the component and the effect keep each other alive).
+pub struct Effect {
+	observer: Rc<Observer>,
+}
+
+impl Effect {
+	pub fn new(run: impl FnMut() + 'static) -> Self {
+		Self { observer: Observer::new(false, run) }
+	}
+	pub(super) fn memo(run: impl FnMut() + 'static) -> Self {
+		Self { observer: Observer::new(true, run) }
+	}
+	/// An effect that [updates](ComponentContent::update) the component whenever the signals its update reads change.
+	///
+	/// Only holds a weak reference to the component; failed updates are reported to the handler.
+	pub fn updating<C: ComponentContent + 'static>(
+		content: &Rc<C>,
+		handler: impl ErrorHandler<UpdateError> + 'static,
+	) -> Self {
+		let content: Weak<C> = Rc::downgrade(content);
+		Self::new(move || {
+			let Some(content) = content.upgrade() else { return };
+			if let Err(error) = content.update() {
+				handler.handle(UpdateError(error));
+			}
+		})
+	}
+
+	/// Stops the effect (it won't run again and its code is dropped).
+	pub fn dispose(&self) {
+		self.observer.dispose();
+	}
+}
+
+impl Drop for Effect {
+	fn drop(&mut self) {
+		self.observer.dispose();
+	}
+}
+
+/// The effects owned by a component.
+///
+/// # Example
+/// ```text
+/// impl ComponentContent for Counter {
+///     ...
+///     fn destroy(&self) -> Result<()> {
+///         self.effects.dispose();
+///         Ok(())
+///     }
+/// }
+/// ```
+#[derive(Default)]
+pub struct Effects {
+	effects: RefCell<Vec<Effect>>,
+}
+
+impl Effects {
+	pub fn new() -> Self {
+		Self { effects: RefCell::new(Vec::new()) }
+	}
+	pub fn add(&self, effect: Effect) {
+		self.effects.borrow_mut().push(effect);
+	}
+	/// Creates an [Effect] and adds it.
+	pub fn create(&self, run: impl FnMut() + 'static) {
+		self.add(Effect::new(run));
+	}
+	/// Stops all effects (and drops them).
+	pub fn dispose(&self) {
+		let effects = std::mem::take(&mut *self.effects.borrow_mut());
+		drop(effects);
+	}
+	pub fn len(&self) -> usize {
+		self.effects.borrow().len()
+	}
+	pub fn is_empty(&self) -> bool {
+		self.effects.borrow().is_empty()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::reactive::{batch, Memo, Signal};
+
+	fn counting<T: Clone + 'static>(read: impl Fn() -> T + 'static) -> (Effect, Rc<RefCell<Vec<T>>>) {
+		let seen = Rc::new(RefCell::new(Vec::new()));
+		let effect = Effect::new({
+			let seen = seen.clone();
+			move || seen.borrow_mut().push(read())
+		});
+		(effect, seen)
+	}
+
+	#[test]
+	fn reruns_when_signal_changes() {
+		let signal = Signal::new(1);
+		let (effect, seen) = counting({
+			let signal = signal.clone();
+			move || signal.get()
+		});
+		signal.set(2);
+		effect.dispose();
+		signal.set(3);
+		assert_eq!(*seen.borrow(), vec![1, 2]);
+	}
+
+	#[test]
+	fn batches_writes() {
+		let a = Signal::new(1);
+		let b = Signal::new(10);
+		let (_effect, seen) = counting({
+			let (a, b) = (a.clone(), b.clone());
+			move || a.get() + b.get()
+		});
+		batch(|| {
+			a.set(2);
+			b.set(20);
+			a.set(3);
+		});
+		assert_eq!(*seen.borrow(), vec![11, 23]);
+	}
+
+	#[test]
+	fn memo_only_notifies_on_change() {
+		let count = Signal::new(1);
+		let parity = Memo::new({
+			let count = count.clone();
+			move || count.get() % 2
+		});
+		let (_effect, seen) = counting({
+			let parity = parity.clone();
+			move || parity.get()
+		});
+		count.set(3);
+		count.set(4);
+		assert_eq!(*seen.borrow(), vec![1, 0]);
+	}
+
+	#[test]
+	fn tracks_dependencies_dynamically() {
+		let condition = Signal::new(true);
+		let a = Signal::new("a");
+		let b = Signal::new("b");
+		let (_effect, seen) = counting({
+			let (condition, a, b) = (condition.clone(), a.clone(), b.clone());
+			move || match condition.get() {
+				true => a.get(),
+				false => b.get(),
+			}
+		});
+		condition.set(false);
+		a.set("A"); // not read anymore
+		b.set("B");
+		assert_eq!(*seen.borrow(), vec!["a", "b", "B"]);
+	}
+}
@@ -0,0 +1,60 @@
+use std::{cell::RefCell, rc::Rc};
+
+use super::{runtime::Source, Effect};
+
+/// A value computed from signals (or other memos).
+///
+/// It's recomputed when one of them changes, but only notifies its own dependents if the result is different.
+/// Clones share the value; the computation stops when the last clone is dropped.
+pub struct Memo<T> {
+	value: Rc<RefCell<Option<T>>>,
+	source: Rc<Source>,
+	_effect: Rc<Effect>,
+}
+
+impl<T: PartialEq + 'static> Memo<T> {
+	/// Creates the memo and computes its value right away.
+	pub fn new(compute: impl Fn() -> T + 'static) -> Self {
+		let value = Rc::new(RefCell::new(None::<T>));
+		let source = Rc::new(Source::default());
+		let effect = Effect::memo({
+			let value = value.clone();
+			let source = source.clone();
+			move || {
+				let new = compute();
+				let initialized = value.borrow().is_some();
+				if value.borrow().as_ref() == Some(&new) {
+					return;
+				}
+				*value.borrow_mut() = Some(new);
+				if initialized {
+					source.notify();
+				}
+			}
+		});
+		Self { value, source, _effect: Rc::new(effect) }
+	}
+}
+
+impl<T> Memo<T> {
+	/// Reads the value (and makes the running effect depend on it).
+	pub fn get(&self) -> T
+	where
+		T: Clone,
+	{
+		self.with(T::clone)
+	}
+	/// Reads the value without cloning it (and makes the running effect depend on it).
+	pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+		self.source.track();
+		let value = self.value.borrow();
+		// the value is computed when the memo is created, so it is always there
+		f(value.as_ref().expect("memo has no value"))
+	}
+}
+
+impl<T> Clone for Memo<T> {
+	fn clone(&self) -> Self {
+		Self { value: self.value.clone(), source: self.source.clone(), _effect: self._effect.clone() }
+	}
+}
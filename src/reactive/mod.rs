@@ -0,0 +1,35 @@
+//! Reactive state that lives alongside the `Rc`/`RefCell` model of the components.
+//!
+//! - [Signal]: a value that notifies whoever read it when it changes
+//! - [Memo]: a value derived from signals (recomputed only when they change)
+//! - [Effect]: code that reruns whenever the signals it read change
+//! - [Effects]: the effects a [ComponentContent](crate::ComponentContent) owns (dispose them in `destroy()`)
+//!
+//! Dependencies are tracked automatically: whatever an effect reads while it runs is what it depends on.
+//! Writes inside [batch] only rerun the effects once the batch ends (every effect at most once).
+//!
+//! # Example
+//! ```text
+//! let count = Signal::new(0);
+//! let double = Memo::new({
+//!     let count = count.clone();
+//!     move || count.get() * 2
+//! });
+//! let effect = Effect::new(move || log(double.get())); // logs 0
+//! batch(|| {
+//!     count.set(1);
+//!     count.set(2);
+//! }); // logs 4 (once)
+//! ```
+
+mod runtime;
+pub use runtime::{batch, untracked};
+
+mod signal;
+pub use signal::*;
+
+mod memo;
+pub use memo::*;
+
+mod effect;
+pub use effect::*;
@@ -0,0 +1,150 @@
+use std::{
+	cell::{Cell, RefCell},
+	rc::{Rc, Weak},
+};
+
+thread_local! {
+	/// the observers that are currently running (`None` while [untracked])
+	static RUNNING: RefCell<Vec<Option<Rc<Observer>>>> = const { RefCell::new(Vec::new()) };
+	static BATCH_DEPTH: Cell<usize> = const { Cell::new(0) };
+	static PENDING: RefCell<Vec<Rc<Observer>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Runs the function and reruns the effects affected by its writes only once it's done.
+///
+/// Batches can be nested (the effects run when the outermost one ends).
+pub fn batch<R>(f: impl FnOnce() -> R) -> R {
+	BATCH_DEPTH.with(|depth| depth.set(depth.get() + 1));
+	let result = f();
+	let depth = BATCH_DEPTH.with(|depth| {
+		depth.set(depth.get() - 1);
+		depth.get()
+	});
+	if depth == 0 {
+		flush();
+	}
+	result
+}
+
+/// Runs the function without tracking what it reads (so the running effect doesn't depend on it).
+pub fn untracked<R>(f: impl FnOnce() -> R) -> R {
+	RUNNING.with(|running| running.borrow_mut().push(None));
+	let result = f();
+	RUNNING.with(|running| running.borrow_mut().pop());
+	result
+}
+
+/// Runs the pending observers (memos first, so effects see their new values) until there are none left.
+fn flush() {
+	// writes of the observers only schedule more observers, this loop runs them
+	BATCH_DEPTH.with(|depth| depth.set(depth.get() + 1));
+	while let Some(observer) = PENDING.with(|pending| {
+		let mut pending = pending.borrow_mut();
+		let index = pending.iter().position(|observer| observer.memo).unwrap_or(0);
+		(!pending.is_empty()).then(|| pending.remove(index))
+	}) {
+		observer.run();
+	}
+	BATCH_DEPTH.with(|depth| depth.set(depth.get() - 1));
+}
+
+/// Something that can be read (and tracked) by observers.
+#[derive(Default)]
+pub(super) struct Source {
+	observers: RefCell<Vec<Weak<Observer>>>,
+}
+
+impl Source {
+	/// Makes the running observer (if any) depend on this.
+	pub fn track(self: &Rc<Self>) {
+		let Some(Some(observer)) = RUNNING.with(|running| running.borrow().last().cloned()) else { return };
+		let mut observers = self.observers.borrow_mut();
+		if observers.iter().any(|existing| existing.as_ptr() == Rc::as_ptr(&observer)) {
+			return;
+		}
+		observers.push(Rc::downgrade(&observer));
+		observer.sources.borrow_mut().push(self.clone());
+	}
+	/// Reruns the observers that depend on this (once the current batch ends).
+	pub fn notify(&self) {
+		let observers: Vec<Rc<Observer>> = {
+			let mut observers = self.observers.borrow_mut();
+			observers.retain(|observer| observer.strong_count() > 0);
+			observers.iter().filter_map(Weak::upgrade).collect()
+		};
+		batch(|| {
+			for observer in observers {
+				observer.schedule();
+			}
+		});
+	}
+	fn remove(&self, observer: &Observer) {
+		self.observers.borrow_mut().retain(|existing| existing.as_ptr() != observer as *const Observer);
+	}
+}
+
+/// The code of a [Memo](super::Memo) or [Effect](super::Effect) and the sources it read last time it ran.
+pub(super) struct Observer {
+	/// memos run before effects
+	memo: bool,
+	disposed: Cell<bool>,
+	// taken out while running (so an observer can't rerun itself)
+	run: RefCell<Option<Box<dyn FnMut()>>>,
+	sources: RefCell<Vec<Rc<Source>>>,
+}
+
+impl Observer {
+	/// Creates the observer and runs it for the first time (to track its dependencies).
+	pub fn new(memo: bool, run: impl FnMut() + 'static) -> Rc<Self> {
+		let observer = Rc::new(Self {
+			memo,
+			disposed: Cell::new(false),
+			run: RefCell::new(Some(Box::new(run))),
+			sources: RefCell::new(Vec::new()),
+		});
+		observer.run();
+		observer
+	}
+
+	fn schedule(self: Rc<Self>) {
+		if self.disposed.get() {
+			return;
+		}
+		PENDING.with(|pending| {
+			let mut pending = pending.borrow_mut();
+			if !pending.iter().any(|existing| Rc::ptr_eq(existing, &self)) {
+				pending.push(self);
+			}
+		});
+	}
+
+	fn run(self: &Rc<Self>) {
+		if self.disposed.get() {
+			return;
+		}
+		let Some(mut run) = self.run.borrow_mut().take() else { return };
+		// the dependencies are tracked again from scratch
+		self.unsubscribe();
+		RUNNING.with(|running| running.borrow_mut().push(Some(self.clone())));
+		run();
+		RUNNING.with(|running| running.borrow_mut().pop());
+		if !self.disposed.get() {
+			*self.run.borrow_mut() = Some(run);
+		}
+	}
+
+	/// Stops running the observer (and drops its code).
+	pub fn dispose(&self) {
+		self.disposed.set(true);
+		self.unsubscribe();
+		let run = self.run.borrow_mut().take();
+		drop(run);
+	}
+
+	fn unsubscribe(&self) {
+		let sources = std::mem::take(&mut *self.sources.borrow_mut());
+		for source in sources {
+			source.remove(self);
+		}
+	}
+}
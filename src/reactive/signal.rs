@@ -0,0 +1,77 @@
+use std::{cell::RefCell, rc::Rc};
+
+use super::runtime::Source;
+
+/// A value that reruns the [Effect](super::Effect)s (and [Memo](super::Memo)s) that read it when it changes.
+///
+/// Clones share the value.
+///
+/// # Panics
+/// Writing the signal while it is borrowed by [Signal::with] panics.
+pub struct Signal<T> {
+	value: Rc<RefCell<T>>,
+	source: Rc<Source>,
+}
+
+impl<T> Signal<T> {
+	pub fn new(value: T) -> Self {
+		Self { value: Rc::new(RefCell::new(value)), source: Rc::new(Source::default()) }
+	}
+
+	/// Reads the value (and makes the running effect depend on it).
+	pub fn get(&self) -> T
+	where
+		T: Clone,
+	{
+		self.with(T::clone)
+	}
+	/// Reads the value without cloning it (and makes the running effect depend on it).
+	pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+		self.source.track();
+		f(&self.value.borrow())
+	}
+	/// Reads the value without making the running effect depend on it.
+	pub fn get_untracked(&self) -> T
+	where
+		T: Clone,
+	{
+		self.value.borrow().clone()
+	}
+
+	/// Replaces the value and reruns whatever depends on it.
+	pub fn set(&self, value: T) {
+		*self.value.borrow_mut() = value;
+		self.source.notify();
+	}
+	/// Changes the value in place and reruns whatever depends on it.
+	pub fn update(&self, f: impl FnOnce(&mut T)) {
+		f(&mut self.value.borrow_mut());
+		self.source.notify();
+	}
+	/// Like [Signal::set], but only reruns the dependents if the value is different.
+	///
+	/// # Returns
+	/// Whether the value changed.
+	pub fn set_if_changed(&self, value: T) -> bool
+	where
+		T: PartialEq,
+	{
+		if *self.value.borrow() == value {
+			return false;
+		}
+		self.set(value);
+		true
+	}
+}
+
+impl<T> Clone for Signal<T> {
+	fn clone(&self) -> Self {
+		Self { value: self.value.clone(), source: self.source.clone() }
+	}
+}
+
+impl<T: Default> Default for Signal<T> {
+	fn default() -> Self {
+		Self::new(T::default())
+	}
+}